@@ -0,0 +1,777 @@
+//! Unicode case folding and case mapping tables.
+//!
+//! The tables are generated from `CaseFolding.txt` and `UnicodeData.txt` of Unicode 14.0.0 and
+//! must be kept sorted by code point, since lookups use binary search.
+
+/// Returns the full case folding of a [`char`], if it folds to more than one [`char`].
+///
+/// Characters without a full case folding of their own fold according to [`fold_simple`].
+#[inline]
+pub(crate) fn fold_full(c: char) -> Option<&'static [char]> {
+    FULL_CASE_FOLDING
+        .binary_search_by_key(&c, |&(k, _)| k)
+        .ok()
+        .map(|i| FULL_CASE_FOLDING[i].1)
+}
+
+/// Returns the simple case folding of a [`char`].
+///
+/// Characters that have no case folding are returned unchanged.
+#[inline]
+pub(crate) fn fold_simple(c: char) -> char {
+    // Fast path: ASCII only folds upper case letters
+    if c.is_ascii() {
+        return c.to_ascii_lowercase();
+    }
+    SIMPLE_CASE_FOLDING
+        .binary_search_by_key(&c, |&(k, _)| k)
+        .map_or(c, |i| SIMPLE_CASE_FOLDING[i].1)
+}
+
+/// Common (`C`) and simple (`S`) case foldings.
+#[rustfmt::skip]
+static SIMPLE_CASE_FOLDING: &[(char, char)] = &[
+    ('\u{41}', '\u{61}'), ('\u{42}', '\u{62}'), ('\u{43}', '\u{63}'), ('\u{44}', '\u{64}'),
+    ('\u{45}', '\u{65}'), ('\u{46}', '\u{66}'), ('\u{47}', '\u{67}'), ('\u{48}', '\u{68}'),
+    ('\u{49}', '\u{69}'), ('\u{4a}', '\u{6a}'), ('\u{4b}', '\u{6b}'), ('\u{4c}', '\u{6c}'),
+    ('\u{4d}', '\u{6d}'), ('\u{4e}', '\u{6e}'), ('\u{4f}', '\u{6f}'), ('\u{50}', '\u{70}'),
+    ('\u{51}', '\u{71}'), ('\u{52}', '\u{72}'), ('\u{53}', '\u{73}'), ('\u{54}', '\u{74}'),
+    ('\u{55}', '\u{75}'), ('\u{56}', '\u{76}'), ('\u{57}', '\u{77}'), ('\u{58}', '\u{78}'),
+    ('\u{59}', '\u{79}'), ('\u{5a}', '\u{7a}'), ('\u{b5}', '\u{3bc}'), ('\u{c0}', '\u{e0}'),
+    ('\u{c1}', '\u{e1}'), ('\u{c2}', '\u{e2}'), ('\u{c3}', '\u{e3}'), ('\u{c4}', '\u{e4}'),
+    ('\u{c5}', '\u{e5}'), ('\u{c6}', '\u{e6}'), ('\u{c7}', '\u{e7}'), ('\u{c8}', '\u{e8}'),
+    ('\u{c9}', '\u{e9}'), ('\u{ca}', '\u{ea}'), ('\u{cb}', '\u{eb}'), ('\u{cc}', '\u{ec}'),
+    ('\u{cd}', '\u{ed}'), ('\u{ce}', '\u{ee}'), ('\u{cf}', '\u{ef}'), ('\u{d0}', '\u{f0}'),
+    ('\u{d1}', '\u{f1}'), ('\u{d2}', '\u{f2}'), ('\u{d3}', '\u{f3}'), ('\u{d4}', '\u{f4}'),
+    ('\u{d5}', '\u{f5}'), ('\u{d6}', '\u{f6}'), ('\u{d8}', '\u{f8}'), ('\u{d9}', '\u{f9}'),
+    ('\u{da}', '\u{fa}'), ('\u{db}', '\u{fb}'), ('\u{dc}', '\u{fc}'), ('\u{dd}', '\u{fd}'),
+    ('\u{de}', '\u{fe}'), ('\u{100}', '\u{101}'), ('\u{102}', '\u{103}'), ('\u{104}', '\u{105}'),
+    ('\u{106}', '\u{107}'), ('\u{108}', '\u{109}'), ('\u{10a}', '\u{10b}'), ('\u{10c}', '\u{10d}'),
+    ('\u{10e}', '\u{10f}'), ('\u{110}', '\u{111}'), ('\u{112}', '\u{113}'), ('\u{114}', '\u{115}'),
+    ('\u{116}', '\u{117}'), ('\u{118}', '\u{119}'), ('\u{11a}', '\u{11b}'), ('\u{11c}', '\u{11d}'),
+    ('\u{11e}', '\u{11f}'), ('\u{120}', '\u{121}'), ('\u{122}', '\u{123}'), ('\u{124}', '\u{125}'),
+    ('\u{126}', '\u{127}'), ('\u{128}', '\u{129}'), ('\u{12a}', '\u{12b}'), ('\u{12c}', '\u{12d}'),
+    ('\u{12e}', '\u{12f}'), ('\u{132}', '\u{133}'), ('\u{134}', '\u{135}'), ('\u{136}', '\u{137}'),
+    ('\u{139}', '\u{13a}'), ('\u{13b}', '\u{13c}'), ('\u{13d}', '\u{13e}'), ('\u{13f}', '\u{140}'),
+    ('\u{141}', '\u{142}'), ('\u{143}', '\u{144}'), ('\u{145}', '\u{146}'), ('\u{147}', '\u{148}'),
+    ('\u{14a}', '\u{14b}'), ('\u{14c}', '\u{14d}'), ('\u{14e}', '\u{14f}'), ('\u{150}', '\u{151}'),
+    ('\u{152}', '\u{153}'), ('\u{154}', '\u{155}'), ('\u{156}', '\u{157}'), ('\u{158}', '\u{159}'),
+    ('\u{15a}', '\u{15b}'), ('\u{15c}', '\u{15d}'), ('\u{15e}', '\u{15f}'), ('\u{160}', '\u{161}'),
+    ('\u{162}', '\u{163}'), ('\u{164}', '\u{165}'), ('\u{166}', '\u{167}'), ('\u{168}', '\u{169}'),
+    ('\u{16a}', '\u{16b}'), ('\u{16c}', '\u{16d}'), ('\u{16e}', '\u{16f}'), ('\u{170}', '\u{171}'),
+    ('\u{172}', '\u{173}'), ('\u{174}', '\u{175}'), ('\u{176}', '\u{177}'), ('\u{178}', '\u{ff}'),
+    ('\u{179}', '\u{17a}'), ('\u{17b}', '\u{17c}'), ('\u{17d}', '\u{17e}'), ('\u{17f}', '\u{73}'),
+    ('\u{181}', '\u{253}'), ('\u{182}', '\u{183}'), ('\u{184}', '\u{185}'), ('\u{186}', '\u{254}'),
+    ('\u{187}', '\u{188}'), ('\u{189}', '\u{256}'), ('\u{18a}', '\u{257}'), ('\u{18b}', '\u{18c}'),
+    ('\u{18e}', '\u{1dd}'), ('\u{18f}', '\u{259}'), ('\u{190}', '\u{25b}'), ('\u{191}', '\u{192}'),
+    ('\u{193}', '\u{260}'), ('\u{194}', '\u{263}'), ('\u{196}', '\u{269}'), ('\u{197}', '\u{268}'),
+    ('\u{198}', '\u{199}'), ('\u{19c}', '\u{26f}'), ('\u{19d}', '\u{272}'), ('\u{19f}', '\u{275}'),
+    ('\u{1a0}', '\u{1a1}'), ('\u{1a2}', '\u{1a3}'), ('\u{1a4}', '\u{1a5}'), ('\u{1a6}', '\u{280}'),
+    ('\u{1a7}', '\u{1a8}'), ('\u{1a9}', '\u{283}'), ('\u{1ac}', '\u{1ad}'), ('\u{1ae}', '\u{288}'),
+    ('\u{1af}', '\u{1b0}'), ('\u{1b1}', '\u{28a}'), ('\u{1b2}', '\u{28b}'), ('\u{1b3}', '\u{1b4}'),
+    ('\u{1b5}', '\u{1b6}'), ('\u{1b7}', '\u{292}'), ('\u{1b8}', '\u{1b9}'), ('\u{1bc}', '\u{1bd}'),
+    ('\u{1c4}', '\u{1c6}'), ('\u{1c5}', '\u{1c6}'), ('\u{1c7}', '\u{1c9}'), ('\u{1c8}', '\u{1c9}'),
+    ('\u{1ca}', '\u{1cc}'), ('\u{1cb}', '\u{1cc}'), ('\u{1cd}', '\u{1ce}'), ('\u{1cf}', '\u{1d0}'),
+    ('\u{1d1}', '\u{1d2}'), ('\u{1d3}', '\u{1d4}'), ('\u{1d5}', '\u{1d6}'), ('\u{1d7}', '\u{1d8}'),
+    ('\u{1d9}', '\u{1da}'), ('\u{1db}', '\u{1dc}'), ('\u{1de}', '\u{1df}'), ('\u{1e0}', '\u{1e1}'),
+    ('\u{1e2}', '\u{1e3}'), ('\u{1e4}', '\u{1e5}'), ('\u{1e6}', '\u{1e7}'), ('\u{1e8}', '\u{1e9}'),
+    ('\u{1ea}', '\u{1eb}'), ('\u{1ec}', '\u{1ed}'), ('\u{1ee}', '\u{1ef}'), ('\u{1f1}', '\u{1f3}'),
+    ('\u{1f2}', '\u{1f3}'), ('\u{1f4}', '\u{1f5}'), ('\u{1f6}', '\u{195}'), ('\u{1f7}', '\u{1bf}'),
+    ('\u{1f8}', '\u{1f9}'), ('\u{1fa}', '\u{1fb}'), ('\u{1fc}', '\u{1fd}'), ('\u{1fe}', '\u{1ff}'),
+    ('\u{200}', '\u{201}'), ('\u{202}', '\u{203}'), ('\u{204}', '\u{205}'), ('\u{206}', '\u{207}'),
+    ('\u{208}', '\u{209}'), ('\u{20a}', '\u{20b}'), ('\u{20c}', '\u{20d}'), ('\u{20e}', '\u{20f}'),
+    ('\u{210}', '\u{211}'), ('\u{212}', '\u{213}'), ('\u{214}', '\u{215}'), ('\u{216}', '\u{217}'),
+    ('\u{218}', '\u{219}'), ('\u{21a}', '\u{21b}'), ('\u{21c}', '\u{21d}'), ('\u{21e}', '\u{21f}'),
+    ('\u{220}', '\u{19e}'), ('\u{222}', '\u{223}'), ('\u{224}', '\u{225}'), ('\u{226}', '\u{227}'),
+    ('\u{228}', '\u{229}'), ('\u{22a}', '\u{22b}'), ('\u{22c}', '\u{22d}'), ('\u{22e}', '\u{22f}'),
+    ('\u{230}', '\u{231}'), ('\u{232}', '\u{233}'), ('\u{23a}', '\u{2c65}'), ('\u{23b}', '\u{23c}'),
+    ('\u{23d}', '\u{19a}'), ('\u{23e}', '\u{2c66}'), ('\u{241}', '\u{242}'), ('\u{243}', '\u{180}'),
+    ('\u{244}', '\u{289}'), ('\u{245}', '\u{28c}'), ('\u{246}', '\u{247}'), ('\u{248}', '\u{249}'),
+    ('\u{24a}', '\u{24b}'), ('\u{24c}', '\u{24d}'), ('\u{24e}', '\u{24f}'), ('\u{345}', '\u{3b9}'),
+    ('\u{370}', '\u{371}'), ('\u{372}', '\u{373}'), ('\u{376}', '\u{377}'), ('\u{37f}', '\u{3f3}'),
+    ('\u{386}', '\u{3ac}'), ('\u{388}', '\u{3ad}'), ('\u{389}', '\u{3ae}'), ('\u{38a}', '\u{3af}'),
+    ('\u{38c}', '\u{3cc}'), ('\u{38e}', '\u{3cd}'), ('\u{38f}', '\u{3ce}'), ('\u{391}', '\u{3b1}'),
+    ('\u{392}', '\u{3b2}'), ('\u{393}', '\u{3b3}'), ('\u{394}', '\u{3b4}'), ('\u{395}', '\u{3b5}'),
+    ('\u{396}', '\u{3b6}'), ('\u{397}', '\u{3b7}'), ('\u{398}', '\u{3b8}'), ('\u{399}', '\u{3b9}'),
+    ('\u{39a}', '\u{3ba}'), ('\u{39b}', '\u{3bb}'), ('\u{39c}', '\u{3bc}'), ('\u{39d}', '\u{3bd}'),
+    ('\u{39e}', '\u{3be}'), ('\u{39f}', '\u{3bf}'), ('\u{3a0}', '\u{3c0}'), ('\u{3a1}', '\u{3c1}'),
+    ('\u{3a3}', '\u{3c3}'), ('\u{3a4}', '\u{3c4}'), ('\u{3a5}', '\u{3c5}'), ('\u{3a6}', '\u{3c6}'),
+    ('\u{3a7}', '\u{3c7}'), ('\u{3a8}', '\u{3c8}'), ('\u{3a9}', '\u{3c9}'), ('\u{3aa}', '\u{3ca}'),
+    ('\u{3ab}', '\u{3cb}'), ('\u{3c2}', '\u{3c3}'), ('\u{3cf}', '\u{3d7}'), ('\u{3d0}', '\u{3b2}'),
+    ('\u{3d1}', '\u{3b8}'), ('\u{3d5}', '\u{3c6}'), ('\u{3d6}', '\u{3c0}'), ('\u{3d8}', '\u{3d9}'),
+    ('\u{3da}', '\u{3db}'), ('\u{3dc}', '\u{3dd}'), ('\u{3de}', '\u{3df}'), ('\u{3e0}', '\u{3e1}'),
+    ('\u{3e2}', '\u{3e3}'), ('\u{3e4}', '\u{3e5}'), ('\u{3e6}', '\u{3e7}'), ('\u{3e8}', '\u{3e9}'),
+    ('\u{3ea}', '\u{3eb}'), ('\u{3ec}', '\u{3ed}'), ('\u{3ee}', '\u{3ef}'), ('\u{3f0}', '\u{3ba}'),
+    ('\u{3f1}', '\u{3c1}'), ('\u{3f4}', '\u{3b8}'), ('\u{3f5}', '\u{3b5}'), ('\u{3f7}', '\u{3f8}'),
+    ('\u{3f9}', '\u{3f2}'), ('\u{3fa}', '\u{3fb}'), ('\u{3fd}', '\u{37b}'), ('\u{3fe}', '\u{37c}'),
+    ('\u{3ff}', '\u{37d}'), ('\u{400}', '\u{450}'), ('\u{401}', '\u{451}'), ('\u{402}', '\u{452}'),
+    ('\u{403}', '\u{453}'), ('\u{404}', '\u{454}'), ('\u{405}', '\u{455}'), ('\u{406}', '\u{456}'),
+    ('\u{407}', '\u{457}'), ('\u{408}', '\u{458}'), ('\u{409}', '\u{459}'), ('\u{40a}', '\u{45a}'),
+    ('\u{40b}', '\u{45b}'), ('\u{40c}', '\u{45c}'), ('\u{40d}', '\u{45d}'), ('\u{40e}', '\u{45e}'),
+    ('\u{40f}', '\u{45f}'), ('\u{410}', '\u{430}'), ('\u{411}', '\u{431}'), ('\u{412}', '\u{432}'),
+    ('\u{413}', '\u{433}'), ('\u{414}', '\u{434}'), ('\u{415}', '\u{435}'), ('\u{416}', '\u{436}'),
+    ('\u{417}', '\u{437}'), ('\u{418}', '\u{438}'), ('\u{419}', '\u{439}'), ('\u{41a}', '\u{43a}'),
+    ('\u{41b}', '\u{43b}'), ('\u{41c}', '\u{43c}'), ('\u{41d}', '\u{43d}'), ('\u{41e}', '\u{43e}'),
+    ('\u{41f}', '\u{43f}'), ('\u{420}', '\u{440}'), ('\u{421}', '\u{441}'), ('\u{422}', '\u{442}'),
+    ('\u{423}', '\u{443}'), ('\u{424}', '\u{444}'), ('\u{425}', '\u{445}'), ('\u{426}', '\u{446}'),
+    ('\u{427}', '\u{447}'), ('\u{428}', '\u{448}'), ('\u{429}', '\u{449}'), ('\u{42a}', '\u{44a}'),
+    ('\u{42b}', '\u{44b}'), ('\u{42c}', '\u{44c}'), ('\u{42d}', '\u{44d}'), ('\u{42e}', '\u{44e}'),
+    ('\u{42f}', '\u{44f}'), ('\u{460}', '\u{461}'), ('\u{462}', '\u{463}'), ('\u{464}', '\u{465}'),
+    ('\u{466}', '\u{467}'), ('\u{468}', '\u{469}'), ('\u{46a}', '\u{46b}'), ('\u{46c}', '\u{46d}'),
+    ('\u{46e}', '\u{46f}'), ('\u{470}', '\u{471}'), ('\u{472}', '\u{473}'), ('\u{474}', '\u{475}'),
+    ('\u{476}', '\u{477}'), ('\u{478}', '\u{479}'), ('\u{47a}', '\u{47b}'), ('\u{47c}', '\u{47d}'),
+    ('\u{47e}', '\u{47f}'), ('\u{480}', '\u{481}'), ('\u{48a}', '\u{48b}'), ('\u{48c}', '\u{48d}'),
+    ('\u{48e}', '\u{48f}'), ('\u{490}', '\u{491}'), ('\u{492}', '\u{493}'), ('\u{494}', '\u{495}'),
+    ('\u{496}', '\u{497}'), ('\u{498}', '\u{499}'), ('\u{49a}', '\u{49b}'), ('\u{49c}', '\u{49d}'),
+    ('\u{49e}', '\u{49f}'), ('\u{4a0}', '\u{4a1}'), ('\u{4a2}', '\u{4a3}'), ('\u{4a4}', '\u{4a5}'),
+    ('\u{4a6}', '\u{4a7}'), ('\u{4a8}', '\u{4a9}'), ('\u{4aa}', '\u{4ab}'), ('\u{4ac}', '\u{4ad}'),
+    ('\u{4ae}', '\u{4af}'), ('\u{4b0}', '\u{4b1}'), ('\u{4b2}', '\u{4b3}'), ('\u{4b4}', '\u{4b5}'),
+    ('\u{4b6}', '\u{4b7}'), ('\u{4b8}', '\u{4b9}'), ('\u{4ba}', '\u{4bb}'), ('\u{4bc}', '\u{4bd}'),
+    ('\u{4be}', '\u{4bf}'), ('\u{4c0}', '\u{4cf}'), ('\u{4c1}', '\u{4c2}'), ('\u{4c3}', '\u{4c4}'),
+    ('\u{4c5}', '\u{4c6}'), ('\u{4c7}', '\u{4c8}'), ('\u{4c9}', '\u{4ca}'), ('\u{4cb}', '\u{4cc}'),
+    ('\u{4cd}', '\u{4ce}'), ('\u{4d0}', '\u{4d1}'), ('\u{4d2}', '\u{4d3}'), ('\u{4d4}', '\u{4d5}'),
+    ('\u{4d6}', '\u{4d7}'), ('\u{4d8}', '\u{4d9}'), ('\u{4da}', '\u{4db}'), ('\u{4dc}', '\u{4dd}'),
+    ('\u{4de}', '\u{4df}'), ('\u{4e0}', '\u{4e1}'), ('\u{4e2}', '\u{4e3}'), ('\u{4e4}', '\u{4e5}'),
+    ('\u{4e6}', '\u{4e7}'), ('\u{4e8}', '\u{4e9}'), ('\u{4ea}', '\u{4eb}'), ('\u{4ec}', '\u{4ed}'),
+    ('\u{4ee}', '\u{4ef}'), ('\u{4f0}', '\u{4f1}'), ('\u{4f2}', '\u{4f3}'), ('\u{4f4}', '\u{4f5}'),
+    ('\u{4f6}', '\u{4f7}'), ('\u{4f8}', '\u{4f9}'), ('\u{4fa}', '\u{4fb}'), ('\u{4fc}', '\u{4fd}'),
+    ('\u{4fe}', '\u{4ff}'), ('\u{500}', '\u{501}'), ('\u{502}', '\u{503}'), ('\u{504}', '\u{505}'),
+    ('\u{506}', '\u{507}'), ('\u{508}', '\u{509}'), ('\u{50a}', '\u{50b}'), ('\u{50c}', '\u{50d}'),
+    ('\u{50e}', '\u{50f}'), ('\u{510}', '\u{511}'), ('\u{512}', '\u{513}'), ('\u{514}', '\u{515}'),
+    ('\u{516}', '\u{517}'), ('\u{518}', '\u{519}'), ('\u{51a}', '\u{51b}'), ('\u{51c}', '\u{51d}'),
+    ('\u{51e}', '\u{51f}'), ('\u{520}', '\u{521}'), ('\u{522}', '\u{523}'), ('\u{524}', '\u{525}'),
+    ('\u{526}', '\u{527}'), ('\u{528}', '\u{529}'), ('\u{52a}', '\u{52b}'), ('\u{52c}', '\u{52d}'),
+    ('\u{52e}', '\u{52f}'), ('\u{531}', '\u{561}'), ('\u{532}', '\u{562}'), ('\u{533}', '\u{563}'),
+    ('\u{534}', '\u{564}'), ('\u{535}', '\u{565}'), ('\u{536}', '\u{566}'), ('\u{537}', '\u{567}'),
+    ('\u{538}', '\u{568}'), ('\u{539}', '\u{569}'), ('\u{53a}', '\u{56a}'), ('\u{53b}', '\u{56b}'),
+    ('\u{53c}', '\u{56c}'), ('\u{53d}', '\u{56d}'), ('\u{53e}', '\u{56e}'), ('\u{53f}', '\u{56f}'),
+    ('\u{540}', '\u{570}'), ('\u{541}', '\u{571}'), ('\u{542}', '\u{572}'), ('\u{543}', '\u{573}'),
+    ('\u{544}', '\u{574}'), ('\u{545}', '\u{575}'), ('\u{546}', '\u{576}'), ('\u{547}', '\u{577}'),
+    ('\u{548}', '\u{578}'), ('\u{549}', '\u{579}'), ('\u{54a}', '\u{57a}'), ('\u{54b}', '\u{57b}'),
+    ('\u{54c}', '\u{57c}'), ('\u{54d}', '\u{57d}'), ('\u{54e}', '\u{57e}'), ('\u{54f}', '\u{57f}'),
+    ('\u{550}', '\u{580}'), ('\u{551}', '\u{581}'), ('\u{552}', '\u{582}'), ('\u{553}', '\u{583}'),
+    ('\u{554}', '\u{584}'), ('\u{555}', '\u{585}'), ('\u{556}', '\u{586}'),
+    ('\u{10a0}', '\u{2d00}'), ('\u{10a1}', '\u{2d01}'), ('\u{10a2}', '\u{2d02}'),
+    ('\u{10a3}', '\u{2d03}'), ('\u{10a4}', '\u{2d04}'), ('\u{10a5}', '\u{2d05}'),
+    ('\u{10a6}', '\u{2d06}'), ('\u{10a7}', '\u{2d07}'), ('\u{10a8}', '\u{2d08}'),
+    ('\u{10a9}', '\u{2d09}'), ('\u{10aa}', '\u{2d0a}'), ('\u{10ab}', '\u{2d0b}'),
+    ('\u{10ac}', '\u{2d0c}'), ('\u{10ad}', '\u{2d0d}'), ('\u{10ae}', '\u{2d0e}'),
+    ('\u{10af}', '\u{2d0f}'), ('\u{10b0}', '\u{2d10}'), ('\u{10b1}', '\u{2d11}'),
+    ('\u{10b2}', '\u{2d12}'), ('\u{10b3}', '\u{2d13}'), ('\u{10b4}', '\u{2d14}'),
+    ('\u{10b5}', '\u{2d15}'), ('\u{10b6}', '\u{2d16}'), ('\u{10b7}', '\u{2d17}'),
+    ('\u{10b8}', '\u{2d18}'), ('\u{10b9}', '\u{2d19}'), ('\u{10ba}', '\u{2d1a}'),
+    ('\u{10bb}', '\u{2d1b}'), ('\u{10bc}', '\u{2d1c}'), ('\u{10bd}', '\u{2d1d}'),
+    ('\u{10be}', '\u{2d1e}'), ('\u{10bf}', '\u{2d1f}'), ('\u{10c0}', '\u{2d20}'),
+    ('\u{10c1}', '\u{2d21}'), ('\u{10c2}', '\u{2d22}'), ('\u{10c3}', '\u{2d23}'),
+    ('\u{10c4}', '\u{2d24}'), ('\u{10c5}', '\u{2d25}'), ('\u{10c7}', '\u{2d27}'),
+    ('\u{10cd}', '\u{2d2d}'), ('\u{13f8}', '\u{13f0}'), ('\u{13f9}', '\u{13f1}'),
+    ('\u{13fa}', '\u{13f2}'), ('\u{13fb}', '\u{13f3}'), ('\u{13fc}', '\u{13f4}'),
+    ('\u{13fd}', '\u{13f5}'), ('\u{1c80}', '\u{432}'), ('\u{1c81}', '\u{434}'),
+    ('\u{1c82}', '\u{43e}'), ('\u{1c83}', '\u{441}'), ('\u{1c84}', '\u{442}'),
+    ('\u{1c85}', '\u{442}'), ('\u{1c86}', '\u{44a}'), ('\u{1c87}', '\u{463}'),
+    ('\u{1c88}', '\u{a64b}'), ('\u{1c90}', '\u{10d0}'), ('\u{1c91}', '\u{10d1}'),
+    ('\u{1c92}', '\u{10d2}'), ('\u{1c93}', '\u{10d3}'), ('\u{1c94}', '\u{10d4}'),
+    ('\u{1c95}', '\u{10d5}'), ('\u{1c96}', '\u{10d6}'), ('\u{1c97}', '\u{10d7}'),
+    ('\u{1c98}', '\u{10d8}'), ('\u{1c99}', '\u{10d9}'), ('\u{1c9a}', '\u{10da}'),
+    ('\u{1c9b}', '\u{10db}'), ('\u{1c9c}', '\u{10dc}'), ('\u{1c9d}', '\u{10dd}'),
+    ('\u{1c9e}', '\u{10de}'), ('\u{1c9f}', '\u{10df}'), ('\u{1ca0}', '\u{10e0}'),
+    ('\u{1ca1}', '\u{10e1}'), ('\u{1ca2}', '\u{10e2}'), ('\u{1ca3}', '\u{10e3}'),
+    ('\u{1ca4}', '\u{10e4}'), ('\u{1ca5}', '\u{10e5}'), ('\u{1ca6}', '\u{10e6}'),
+    ('\u{1ca7}', '\u{10e7}'), ('\u{1ca8}', '\u{10e8}'), ('\u{1ca9}', '\u{10e9}'),
+    ('\u{1caa}', '\u{10ea}'), ('\u{1cab}', '\u{10eb}'), ('\u{1cac}', '\u{10ec}'),
+    ('\u{1cad}', '\u{10ed}'), ('\u{1cae}', '\u{10ee}'), ('\u{1caf}', '\u{10ef}'),
+    ('\u{1cb0}', '\u{10f0}'), ('\u{1cb1}', '\u{10f1}'), ('\u{1cb2}', '\u{10f2}'),
+    ('\u{1cb3}', '\u{10f3}'), ('\u{1cb4}', '\u{10f4}'), ('\u{1cb5}', '\u{10f5}'),
+    ('\u{1cb6}', '\u{10f6}'), ('\u{1cb7}', '\u{10f7}'), ('\u{1cb8}', '\u{10f8}'),
+    ('\u{1cb9}', '\u{10f9}'), ('\u{1cba}', '\u{10fa}'), ('\u{1cbd}', '\u{10fd}'),
+    ('\u{1cbe}', '\u{10fe}'), ('\u{1cbf}', '\u{10ff}'), ('\u{1e00}', '\u{1e01}'),
+    ('\u{1e02}', '\u{1e03}'), ('\u{1e04}', '\u{1e05}'), ('\u{1e06}', '\u{1e07}'),
+    ('\u{1e08}', '\u{1e09}'), ('\u{1e0a}', '\u{1e0b}'), ('\u{1e0c}', '\u{1e0d}'),
+    ('\u{1e0e}', '\u{1e0f}'), ('\u{1e10}', '\u{1e11}'), ('\u{1e12}', '\u{1e13}'),
+    ('\u{1e14}', '\u{1e15}'), ('\u{1e16}', '\u{1e17}'), ('\u{1e18}', '\u{1e19}'),
+    ('\u{1e1a}', '\u{1e1b}'), ('\u{1e1c}', '\u{1e1d}'), ('\u{1e1e}', '\u{1e1f}'),
+    ('\u{1e20}', '\u{1e21}'), ('\u{1e22}', '\u{1e23}'), ('\u{1e24}', '\u{1e25}'),
+    ('\u{1e26}', '\u{1e27}'), ('\u{1e28}', '\u{1e29}'), ('\u{1e2a}', '\u{1e2b}'),
+    ('\u{1e2c}', '\u{1e2d}'), ('\u{1e2e}', '\u{1e2f}'), ('\u{1e30}', '\u{1e31}'),
+    ('\u{1e32}', '\u{1e33}'), ('\u{1e34}', '\u{1e35}'), ('\u{1e36}', '\u{1e37}'),
+    ('\u{1e38}', '\u{1e39}'), ('\u{1e3a}', '\u{1e3b}'), ('\u{1e3c}', '\u{1e3d}'),
+    ('\u{1e3e}', '\u{1e3f}'), ('\u{1e40}', '\u{1e41}'), ('\u{1e42}', '\u{1e43}'),
+    ('\u{1e44}', '\u{1e45}'), ('\u{1e46}', '\u{1e47}'), ('\u{1e48}', '\u{1e49}'),
+    ('\u{1e4a}', '\u{1e4b}'), ('\u{1e4c}', '\u{1e4d}'), ('\u{1e4e}', '\u{1e4f}'),
+    ('\u{1e50}', '\u{1e51}'), ('\u{1e52}', '\u{1e53}'), ('\u{1e54}', '\u{1e55}'),
+    ('\u{1e56}', '\u{1e57}'), ('\u{1e58}', '\u{1e59}'), ('\u{1e5a}', '\u{1e5b}'),
+    ('\u{1e5c}', '\u{1e5d}'), ('\u{1e5e}', '\u{1e5f}'), ('\u{1e60}', '\u{1e61}'),
+    ('\u{1e62}', '\u{1e63}'), ('\u{1e64}', '\u{1e65}'), ('\u{1e66}', '\u{1e67}'),
+    ('\u{1e68}', '\u{1e69}'), ('\u{1e6a}', '\u{1e6b}'), ('\u{1e6c}', '\u{1e6d}'),
+    ('\u{1e6e}', '\u{1e6f}'), ('\u{1e70}', '\u{1e71}'), ('\u{1e72}', '\u{1e73}'),
+    ('\u{1e74}', '\u{1e75}'), ('\u{1e76}', '\u{1e77}'), ('\u{1e78}', '\u{1e79}'),
+    ('\u{1e7a}', '\u{1e7b}'), ('\u{1e7c}', '\u{1e7d}'), ('\u{1e7e}', '\u{1e7f}'),
+    ('\u{1e80}', '\u{1e81}'), ('\u{1e82}', '\u{1e83}'), ('\u{1e84}', '\u{1e85}'),
+    ('\u{1e86}', '\u{1e87}'), ('\u{1e88}', '\u{1e89}'), ('\u{1e8a}', '\u{1e8b}'),
+    ('\u{1e8c}', '\u{1e8d}'), ('\u{1e8e}', '\u{1e8f}'), ('\u{1e90}', '\u{1e91}'),
+    ('\u{1e92}', '\u{1e93}'), ('\u{1e94}', '\u{1e95}'), ('\u{1e9b}', '\u{1e61}'),
+    ('\u{1e9e}', '\u{df}'), ('\u{1ea0}', '\u{1ea1}'), ('\u{1ea2}', '\u{1ea3}'),
+    ('\u{1ea4}', '\u{1ea5}'), ('\u{1ea6}', '\u{1ea7}'), ('\u{1ea8}', '\u{1ea9}'),
+    ('\u{1eaa}', '\u{1eab}'), ('\u{1eac}', '\u{1ead}'), ('\u{1eae}', '\u{1eaf}'),
+    ('\u{1eb0}', '\u{1eb1}'), ('\u{1eb2}', '\u{1eb3}'), ('\u{1eb4}', '\u{1eb5}'),
+    ('\u{1eb6}', '\u{1eb7}'), ('\u{1eb8}', '\u{1eb9}'), ('\u{1eba}', '\u{1ebb}'),
+    ('\u{1ebc}', '\u{1ebd}'), ('\u{1ebe}', '\u{1ebf}'), ('\u{1ec0}', '\u{1ec1}'),
+    ('\u{1ec2}', '\u{1ec3}'), ('\u{1ec4}', '\u{1ec5}'), ('\u{1ec6}', '\u{1ec7}'),
+    ('\u{1ec8}', '\u{1ec9}'), ('\u{1eca}', '\u{1ecb}'), ('\u{1ecc}', '\u{1ecd}'),
+    ('\u{1ece}', '\u{1ecf}'), ('\u{1ed0}', '\u{1ed1}'), ('\u{1ed2}', '\u{1ed3}'),
+    ('\u{1ed4}', '\u{1ed5}'), ('\u{1ed6}', '\u{1ed7}'), ('\u{1ed8}', '\u{1ed9}'),
+    ('\u{1eda}', '\u{1edb}'), ('\u{1edc}', '\u{1edd}'), ('\u{1ede}', '\u{1edf}'),
+    ('\u{1ee0}', '\u{1ee1}'), ('\u{1ee2}', '\u{1ee3}'), ('\u{1ee4}', '\u{1ee5}'),
+    ('\u{1ee6}', '\u{1ee7}'), ('\u{1ee8}', '\u{1ee9}'), ('\u{1eea}', '\u{1eeb}'),
+    ('\u{1eec}', '\u{1eed}'), ('\u{1eee}', '\u{1eef}'), ('\u{1ef0}', '\u{1ef1}'),
+    ('\u{1ef2}', '\u{1ef3}'), ('\u{1ef4}', '\u{1ef5}'), ('\u{1ef6}', '\u{1ef7}'),
+    ('\u{1ef8}', '\u{1ef9}'), ('\u{1efa}', '\u{1efb}'), ('\u{1efc}', '\u{1efd}'),
+    ('\u{1efe}', '\u{1eff}'), ('\u{1f08}', '\u{1f00}'), ('\u{1f09}', '\u{1f01}'),
+    ('\u{1f0a}', '\u{1f02}'), ('\u{1f0b}', '\u{1f03}'), ('\u{1f0c}', '\u{1f04}'),
+    ('\u{1f0d}', '\u{1f05}'), ('\u{1f0e}', '\u{1f06}'), ('\u{1f0f}', '\u{1f07}'),
+    ('\u{1f18}', '\u{1f10}'), ('\u{1f19}', '\u{1f11}'), ('\u{1f1a}', '\u{1f12}'),
+    ('\u{1f1b}', '\u{1f13}'), ('\u{1f1c}', '\u{1f14}'), ('\u{1f1d}', '\u{1f15}'),
+    ('\u{1f28}', '\u{1f20}'), ('\u{1f29}', '\u{1f21}'), ('\u{1f2a}', '\u{1f22}'),
+    ('\u{1f2b}', '\u{1f23}'), ('\u{1f2c}', '\u{1f24}'), ('\u{1f2d}', '\u{1f25}'),
+    ('\u{1f2e}', '\u{1f26}'), ('\u{1f2f}', '\u{1f27}'), ('\u{1f38}', '\u{1f30}'),
+    ('\u{1f39}', '\u{1f31}'), ('\u{1f3a}', '\u{1f32}'), ('\u{1f3b}', '\u{1f33}'),
+    ('\u{1f3c}', '\u{1f34}'), ('\u{1f3d}', '\u{1f35}'), ('\u{1f3e}', '\u{1f36}'),
+    ('\u{1f3f}', '\u{1f37}'), ('\u{1f48}', '\u{1f40}'), ('\u{1f49}', '\u{1f41}'),
+    ('\u{1f4a}', '\u{1f42}'), ('\u{1f4b}', '\u{1f43}'), ('\u{1f4c}', '\u{1f44}'),
+    ('\u{1f4d}', '\u{1f45}'), ('\u{1f59}', '\u{1f51}'), ('\u{1f5b}', '\u{1f53}'),
+    ('\u{1f5d}', '\u{1f55}'), ('\u{1f5f}', '\u{1f57}'), ('\u{1f68}', '\u{1f60}'),
+    ('\u{1f69}', '\u{1f61}'), ('\u{1f6a}', '\u{1f62}'), ('\u{1f6b}', '\u{1f63}'),
+    ('\u{1f6c}', '\u{1f64}'), ('\u{1f6d}', '\u{1f65}'), ('\u{1f6e}', '\u{1f66}'),
+    ('\u{1f6f}', '\u{1f67}'), ('\u{1f88}', '\u{1f80}'), ('\u{1f89}', '\u{1f81}'),
+    ('\u{1f8a}', '\u{1f82}'), ('\u{1f8b}', '\u{1f83}'), ('\u{1f8c}', '\u{1f84}'),
+    ('\u{1f8d}', '\u{1f85}'), ('\u{1f8e}', '\u{1f86}'), ('\u{1f8f}', '\u{1f87}'),
+    ('\u{1f98}', '\u{1f90}'), ('\u{1f99}', '\u{1f91}'), ('\u{1f9a}', '\u{1f92}'),
+    ('\u{1f9b}', '\u{1f93}'), ('\u{1f9c}', '\u{1f94}'), ('\u{1f9d}', '\u{1f95}'),
+    ('\u{1f9e}', '\u{1f96}'), ('\u{1f9f}', '\u{1f97}'), ('\u{1fa8}', '\u{1fa0}'),
+    ('\u{1fa9}', '\u{1fa1}'), ('\u{1faa}', '\u{1fa2}'), ('\u{1fab}', '\u{1fa3}'),
+    ('\u{1fac}', '\u{1fa4}'), ('\u{1fad}', '\u{1fa5}'), ('\u{1fae}', '\u{1fa6}'),
+    ('\u{1faf}', '\u{1fa7}'), ('\u{1fb8}', '\u{1fb0}'), ('\u{1fb9}', '\u{1fb1}'),
+    ('\u{1fba}', '\u{1f70}'), ('\u{1fbb}', '\u{1f71}'), ('\u{1fbc}', '\u{1fb3}'),
+    ('\u{1fbe}', '\u{3b9}'), ('\u{1fc8}', '\u{1f72}'), ('\u{1fc9}', '\u{1f73}'),
+    ('\u{1fca}', '\u{1f74}'), ('\u{1fcb}', '\u{1f75}'), ('\u{1fcc}', '\u{1fc3}'),
+    ('\u{1fd8}', '\u{1fd0}'), ('\u{1fd9}', '\u{1fd1}'), ('\u{1fda}', '\u{1f76}'),
+    ('\u{1fdb}', '\u{1f77}'), ('\u{1fe8}', '\u{1fe0}'), ('\u{1fe9}', '\u{1fe1}'),
+    ('\u{1fea}', '\u{1f7a}'), ('\u{1feb}', '\u{1f7b}'), ('\u{1fec}', '\u{1fe5}'),
+    ('\u{1ff8}', '\u{1f78}'), ('\u{1ff9}', '\u{1f79}'), ('\u{1ffa}', '\u{1f7c}'),
+    ('\u{1ffb}', '\u{1f7d}'), ('\u{1ffc}', '\u{1ff3}'), ('\u{2126}', '\u{3c9}'),
+    ('\u{212a}', '\u{6b}'), ('\u{212b}', '\u{e5}'), ('\u{2132}', '\u{214e}'),
+    ('\u{2160}', '\u{2170}'), ('\u{2161}', '\u{2171}'), ('\u{2162}', '\u{2172}'),
+    ('\u{2163}', '\u{2173}'), ('\u{2164}', '\u{2174}'), ('\u{2165}', '\u{2175}'),
+    ('\u{2166}', '\u{2176}'), ('\u{2167}', '\u{2177}'), ('\u{2168}', '\u{2178}'),
+    ('\u{2169}', '\u{2179}'), ('\u{216a}', '\u{217a}'), ('\u{216b}', '\u{217b}'),
+    ('\u{216c}', '\u{217c}'), ('\u{216d}', '\u{217d}'), ('\u{216e}', '\u{217e}'),
+    ('\u{216f}', '\u{217f}'), ('\u{2183}', '\u{2184}'), ('\u{24b6}', '\u{24d0}'),
+    ('\u{24b7}', '\u{24d1}'), ('\u{24b8}', '\u{24d2}'), ('\u{24b9}', '\u{24d3}'),
+    ('\u{24ba}', '\u{24d4}'), ('\u{24bb}', '\u{24d5}'), ('\u{24bc}', '\u{24d6}'),
+    ('\u{24bd}', '\u{24d7}'), ('\u{24be}', '\u{24d8}'), ('\u{24bf}', '\u{24d9}'),
+    ('\u{24c0}', '\u{24da}'), ('\u{24c1}', '\u{24db}'), ('\u{24c2}', '\u{24dc}'),
+    ('\u{24c3}', '\u{24dd}'), ('\u{24c4}', '\u{24de}'), ('\u{24c5}', '\u{24df}'),
+    ('\u{24c6}', '\u{24e0}'), ('\u{24c7}', '\u{24e1}'), ('\u{24c8}', '\u{24e2}'),
+    ('\u{24c9}', '\u{24e3}'), ('\u{24ca}', '\u{24e4}'), ('\u{24cb}', '\u{24e5}'),
+    ('\u{24cc}', '\u{24e6}'), ('\u{24cd}', '\u{24e7}'), ('\u{24ce}', '\u{24e8}'),
+    ('\u{24cf}', '\u{24e9}'), ('\u{2c00}', '\u{2c30}'), ('\u{2c01}', '\u{2c31}'),
+    ('\u{2c02}', '\u{2c32}'), ('\u{2c03}', '\u{2c33}'), ('\u{2c04}', '\u{2c34}'),
+    ('\u{2c05}', '\u{2c35}'), ('\u{2c06}', '\u{2c36}'), ('\u{2c07}', '\u{2c37}'),
+    ('\u{2c08}', '\u{2c38}'), ('\u{2c09}', '\u{2c39}'), ('\u{2c0a}', '\u{2c3a}'),
+    ('\u{2c0b}', '\u{2c3b}'), ('\u{2c0c}', '\u{2c3c}'), ('\u{2c0d}', '\u{2c3d}'),
+    ('\u{2c0e}', '\u{2c3e}'), ('\u{2c0f}', '\u{2c3f}'), ('\u{2c10}', '\u{2c40}'),
+    ('\u{2c11}', '\u{2c41}'), ('\u{2c12}', '\u{2c42}'), ('\u{2c13}', '\u{2c43}'),
+    ('\u{2c14}', '\u{2c44}'), ('\u{2c15}', '\u{2c45}'), ('\u{2c16}', '\u{2c46}'),
+    ('\u{2c17}', '\u{2c47}'), ('\u{2c18}', '\u{2c48}'), ('\u{2c19}', '\u{2c49}'),
+    ('\u{2c1a}', '\u{2c4a}'), ('\u{2c1b}', '\u{2c4b}'), ('\u{2c1c}', '\u{2c4c}'),
+    ('\u{2c1d}', '\u{2c4d}'), ('\u{2c1e}', '\u{2c4e}'), ('\u{2c1f}', '\u{2c4f}'),
+    ('\u{2c20}', '\u{2c50}'), ('\u{2c21}', '\u{2c51}'), ('\u{2c22}', '\u{2c52}'),
+    ('\u{2c23}', '\u{2c53}'), ('\u{2c24}', '\u{2c54}'), ('\u{2c25}', '\u{2c55}'),
+    ('\u{2c26}', '\u{2c56}'), ('\u{2c27}', '\u{2c57}'), ('\u{2c28}', '\u{2c58}'),
+    ('\u{2c29}', '\u{2c59}'), ('\u{2c2a}', '\u{2c5a}'), ('\u{2c2b}', '\u{2c5b}'),
+    ('\u{2c2c}', '\u{2c5c}'), ('\u{2c2d}', '\u{2c5d}'), ('\u{2c2e}', '\u{2c5e}'),
+    ('\u{2c2f}', '\u{2c5f}'), ('\u{2c60}', '\u{2c61}'), ('\u{2c62}', '\u{26b}'),
+    ('\u{2c63}', '\u{1d7d}'), ('\u{2c64}', '\u{27d}'), ('\u{2c67}', '\u{2c68}'),
+    ('\u{2c69}', '\u{2c6a}'), ('\u{2c6b}', '\u{2c6c}'), ('\u{2c6d}', '\u{251}'),
+    ('\u{2c6e}', '\u{271}'), ('\u{2c6f}', '\u{250}'), ('\u{2c70}', '\u{252}'),
+    ('\u{2c72}', '\u{2c73}'), ('\u{2c75}', '\u{2c76}'), ('\u{2c7e}', '\u{23f}'),
+    ('\u{2c7f}', '\u{240}'), ('\u{2c80}', '\u{2c81}'), ('\u{2c82}', '\u{2c83}'),
+    ('\u{2c84}', '\u{2c85}'), ('\u{2c86}', '\u{2c87}'), ('\u{2c88}', '\u{2c89}'),
+    ('\u{2c8a}', '\u{2c8b}'), ('\u{2c8c}', '\u{2c8d}'), ('\u{2c8e}', '\u{2c8f}'),
+    ('\u{2c90}', '\u{2c91}'), ('\u{2c92}', '\u{2c93}'), ('\u{2c94}', '\u{2c95}'),
+    ('\u{2c96}', '\u{2c97}'), ('\u{2c98}', '\u{2c99}'), ('\u{2c9a}', '\u{2c9b}'),
+    ('\u{2c9c}', '\u{2c9d}'), ('\u{2c9e}', '\u{2c9f}'), ('\u{2ca0}', '\u{2ca1}'),
+    ('\u{2ca2}', '\u{2ca3}'), ('\u{2ca4}', '\u{2ca5}'), ('\u{2ca6}', '\u{2ca7}'),
+    ('\u{2ca8}', '\u{2ca9}'), ('\u{2caa}', '\u{2cab}'), ('\u{2cac}', '\u{2cad}'),
+    ('\u{2cae}', '\u{2caf}'), ('\u{2cb0}', '\u{2cb1}'), ('\u{2cb2}', '\u{2cb3}'),
+    ('\u{2cb4}', '\u{2cb5}'), ('\u{2cb6}', '\u{2cb7}'), ('\u{2cb8}', '\u{2cb9}'),
+    ('\u{2cba}', '\u{2cbb}'), ('\u{2cbc}', '\u{2cbd}'), ('\u{2cbe}', '\u{2cbf}'),
+    ('\u{2cc0}', '\u{2cc1}'), ('\u{2cc2}', '\u{2cc3}'), ('\u{2cc4}', '\u{2cc5}'),
+    ('\u{2cc6}', '\u{2cc7}'), ('\u{2cc8}', '\u{2cc9}'), ('\u{2cca}', '\u{2ccb}'),
+    ('\u{2ccc}', '\u{2ccd}'), ('\u{2cce}', '\u{2ccf}'), ('\u{2cd0}', '\u{2cd1}'),
+    ('\u{2cd2}', '\u{2cd3}'), ('\u{2cd4}', '\u{2cd5}'), ('\u{2cd6}', '\u{2cd7}'),
+    ('\u{2cd8}', '\u{2cd9}'), ('\u{2cda}', '\u{2cdb}'), ('\u{2cdc}', '\u{2cdd}'),
+    ('\u{2cde}', '\u{2cdf}'), ('\u{2ce0}', '\u{2ce1}'), ('\u{2ce2}', '\u{2ce3}'),
+    ('\u{2ceb}', '\u{2cec}'), ('\u{2ced}', '\u{2cee}'), ('\u{2cf2}', '\u{2cf3}'),
+    ('\u{a640}', '\u{a641}'), ('\u{a642}', '\u{a643}'), ('\u{a644}', '\u{a645}'),
+    ('\u{a646}', '\u{a647}'), ('\u{a648}', '\u{a649}'), ('\u{a64a}', '\u{a64b}'),
+    ('\u{a64c}', '\u{a64d}'), ('\u{a64e}', '\u{a64f}'), ('\u{a650}', '\u{a651}'),
+    ('\u{a652}', '\u{a653}'), ('\u{a654}', '\u{a655}'), ('\u{a656}', '\u{a657}'),
+    ('\u{a658}', '\u{a659}'), ('\u{a65a}', '\u{a65b}'), ('\u{a65c}', '\u{a65d}'),
+    ('\u{a65e}', '\u{a65f}'), ('\u{a660}', '\u{a661}'), ('\u{a662}', '\u{a663}'),
+    ('\u{a664}', '\u{a665}'), ('\u{a666}', '\u{a667}'), ('\u{a668}', '\u{a669}'),
+    ('\u{a66a}', '\u{a66b}'), ('\u{a66c}', '\u{a66d}'), ('\u{a680}', '\u{a681}'),
+    ('\u{a682}', '\u{a683}'), ('\u{a684}', '\u{a685}'), ('\u{a686}', '\u{a687}'),
+    ('\u{a688}', '\u{a689}'), ('\u{a68a}', '\u{a68b}'), ('\u{a68c}', '\u{a68d}'),
+    ('\u{a68e}', '\u{a68f}'), ('\u{a690}', '\u{a691}'), ('\u{a692}', '\u{a693}'),
+    ('\u{a694}', '\u{a695}'), ('\u{a696}', '\u{a697}'), ('\u{a698}', '\u{a699}'),
+    ('\u{a69a}', '\u{a69b}'), ('\u{a722}', '\u{a723}'), ('\u{a724}', '\u{a725}'),
+    ('\u{a726}', '\u{a727}'), ('\u{a728}', '\u{a729}'), ('\u{a72a}', '\u{a72b}'),
+    ('\u{a72c}', '\u{a72d}'), ('\u{a72e}', '\u{a72f}'), ('\u{a732}', '\u{a733}'),
+    ('\u{a734}', '\u{a735}'), ('\u{a736}', '\u{a737}'), ('\u{a738}', '\u{a739}'),
+    ('\u{a73a}', '\u{a73b}'), ('\u{a73c}', '\u{a73d}'), ('\u{a73e}', '\u{a73f}'),
+    ('\u{a740}', '\u{a741}'), ('\u{a742}', '\u{a743}'), ('\u{a744}', '\u{a745}'),
+    ('\u{a746}', '\u{a747}'), ('\u{a748}', '\u{a749}'), ('\u{a74a}', '\u{a74b}'),
+    ('\u{a74c}', '\u{a74d}'), ('\u{a74e}', '\u{a74f}'), ('\u{a750}', '\u{a751}'),
+    ('\u{a752}', '\u{a753}'), ('\u{a754}', '\u{a755}'), ('\u{a756}', '\u{a757}'),
+    ('\u{a758}', '\u{a759}'), ('\u{a75a}', '\u{a75b}'), ('\u{a75c}', '\u{a75d}'),
+    ('\u{a75e}', '\u{a75f}'), ('\u{a760}', '\u{a761}'), ('\u{a762}', '\u{a763}'),
+    ('\u{a764}', '\u{a765}'), ('\u{a766}', '\u{a767}'), ('\u{a768}', '\u{a769}'),
+    ('\u{a76a}', '\u{a76b}'), ('\u{a76c}', '\u{a76d}'), ('\u{a76e}', '\u{a76f}'),
+    ('\u{a779}', '\u{a77a}'), ('\u{a77b}', '\u{a77c}'), ('\u{a77d}', '\u{1d79}'),
+    ('\u{a77e}', '\u{a77f}'), ('\u{a780}', '\u{a781}'), ('\u{a782}', '\u{a783}'),
+    ('\u{a784}', '\u{a785}'), ('\u{a786}', '\u{a787}'), ('\u{a78b}', '\u{a78c}'),
+    ('\u{a78d}', '\u{265}'), ('\u{a790}', '\u{a791}'), ('\u{a792}', '\u{a793}'),
+    ('\u{a796}', '\u{a797}'), ('\u{a798}', '\u{a799}'), ('\u{a79a}', '\u{a79b}'),
+    ('\u{a79c}', '\u{a79d}'), ('\u{a79e}', '\u{a79f}'), ('\u{a7a0}', '\u{a7a1}'),
+    ('\u{a7a2}', '\u{a7a3}'), ('\u{a7a4}', '\u{a7a5}'), ('\u{a7a6}', '\u{a7a7}'),
+    ('\u{a7a8}', '\u{a7a9}'), ('\u{a7aa}', '\u{266}'), ('\u{a7ab}', '\u{25c}'),
+    ('\u{a7ac}', '\u{261}'), ('\u{a7ad}', '\u{26c}'), ('\u{a7ae}', '\u{26a}'),
+    ('\u{a7b0}', '\u{29e}'), ('\u{a7b1}', '\u{287}'), ('\u{a7b2}', '\u{29d}'),
+    ('\u{a7b3}', '\u{ab53}'), ('\u{a7b4}', '\u{a7b5}'), ('\u{a7b6}', '\u{a7b7}'),
+    ('\u{a7b8}', '\u{a7b9}'), ('\u{a7ba}', '\u{a7bb}'), ('\u{a7bc}', '\u{a7bd}'),
+    ('\u{a7be}', '\u{a7bf}'), ('\u{a7c0}', '\u{a7c1}'), ('\u{a7c2}', '\u{a7c3}'),
+    ('\u{a7c4}', '\u{a794}'), ('\u{a7c5}', '\u{282}'), ('\u{a7c6}', '\u{1d8e}'),
+    ('\u{a7c7}', '\u{a7c8}'), ('\u{a7c9}', '\u{a7ca}'), ('\u{a7d0}', '\u{a7d1}'),
+    ('\u{a7d6}', '\u{a7d7}'), ('\u{a7d8}', '\u{a7d9}'), ('\u{a7f5}', '\u{a7f6}'),
+    ('\u{ab70}', '\u{13a0}'), ('\u{ab71}', '\u{13a1}'), ('\u{ab72}', '\u{13a2}'),
+    ('\u{ab73}', '\u{13a3}'), ('\u{ab74}', '\u{13a4}'), ('\u{ab75}', '\u{13a5}'),
+    ('\u{ab76}', '\u{13a6}'), ('\u{ab77}', '\u{13a7}'), ('\u{ab78}', '\u{13a8}'),
+    ('\u{ab79}', '\u{13a9}'), ('\u{ab7a}', '\u{13aa}'), ('\u{ab7b}', '\u{13ab}'),
+    ('\u{ab7c}', '\u{13ac}'), ('\u{ab7d}', '\u{13ad}'), ('\u{ab7e}', '\u{13ae}'),
+    ('\u{ab7f}', '\u{13af}'), ('\u{ab80}', '\u{13b0}'), ('\u{ab81}', '\u{13b1}'),
+    ('\u{ab82}', '\u{13b2}'), ('\u{ab83}', '\u{13b3}'), ('\u{ab84}', '\u{13b4}'),
+    ('\u{ab85}', '\u{13b5}'), ('\u{ab86}', '\u{13b6}'), ('\u{ab87}', '\u{13b7}'),
+    ('\u{ab88}', '\u{13b8}'), ('\u{ab89}', '\u{13b9}'), ('\u{ab8a}', '\u{13ba}'),
+    ('\u{ab8b}', '\u{13bb}'), ('\u{ab8c}', '\u{13bc}'), ('\u{ab8d}', '\u{13bd}'),
+    ('\u{ab8e}', '\u{13be}'), ('\u{ab8f}', '\u{13bf}'), ('\u{ab90}', '\u{13c0}'),
+    ('\u{ab91}', '\u{13c1}'), ('\u{ab92}', '\u{13c2}'), ('\u{ab93}', '\u{13c3}'),
+    ('\u{ab94}', '\u{13c4}'), ('\u{ab95}', '\u{13c5}'), ('\u{ab96}', '\u{13c6}'),
+    ('\u{ab97}', '\u{13c7}'), ('\u{ab98}', '\u{13c8}'), ('\u{ab99}', '\u{13c9}'),
+    ('\u{ab9a}', '\u{13ca}'), ('\u{ab9b}', '\u{13cb}'), ('\u{ab9c}', '\u{13cc}'),
+    ('\u{ab9d}', '\u{13cd}'), ('\u{ab9e}', '\u{13ce}'), ('\u{ab9f}', '\u{13cf}'),
+    ('\u{aba0}', '\u{13d0}'), ('\u{aba1}', '\u{13d1}'), ('\u{aba2}', '\u{13d2}'),
+    ('\u{aba3}', '\u{13d3}'), ('\u{aba4}', '\u{13d4}'), ('\u{aba5}', '\u{13d5}'),
+    ('\u{aba6}', '\u{13d6}'), ('\u{aba7}', '\u{13d7}'), ('\u{aba8}', '\u{13d8}'),
+    ('\u{aba9}', '\u{13d9}'), ('\u{abaa}', '\u{13da}'), ('\u{abab}', '\u{13db}'),
+    ('\u{abac}', '\u{13dc}'), ('\u{abad}', '\u{13dd}'), ('\u{abae}', '\u{13de}'),
+    ('\u{abaf}', '\u{13df}'), ('\u{abb0}', '\u{13e0}'), ('\u{abb1}', '\u{13e1}'),
+    ('\u{abb2}', '\u{13e2}'), ('\u{abb3}', '\u{13e3}'), ('\u{abb4}', '\u{13e4}'),
+    ('\u{abb5}', '\u{13e5}'), ('\u{abb6}', '\u{13e6}'), ('\u{abb7}', '\u{13e7}'),
+    ('\u{abb8}', '\u{13e8}'), ('\u{abb9}', '\u{13e9}'), ('\u{abba}', '\u{13ea}'),
+    ('\u{abbb}', '\u{13eb}'), ('\u{abbc}', '\u{13ec}'), ('\u{abbd}', '\u{13ed}'),
+    ('\u{abbe}', '\u{13ee}'), ('\u{abbf}', '\u{13ef}'), ('\u{ff21}', '\u{ff41}'),
+    ('\u{ff22}', '\u{ff42}'), ('\u{ff23}', '\u{ff43}'), ('\u{ff24}', '\u{ff44}'),
+    ('\u{ff25}', '\u{ff45}'), ('\u{ff26}', '\u{ff46}'), ('\u{ff27}', '\u{ff47}'),
+    ('\u{ff28}', '\u{ff48}'), ('\u{ff29}', '\u{ff49}'), ('\u{ff2a}', '\u{ff4a}'),
+    ('\u{ff2b}', '\u{ff4b}'), ('\u{ff2c}', '\u{ff4c}'), ('\u{ff2d}', '\u{ff4d}'),
+    ('\u{ff2e}', '\u{ff4e}'), ('\u{ff2f}', '\u{ff4f}'), ('\u{ff30}', '\u{ff50}'),
+    ('\u{ff31}', '\u{ff51}'), ('\u{ff32}', '\u{ff52}'), ('\u{ff33}', '\u{ff53}'),
+    ('\u{ff34}', '\u{ff54}'), ('\u{ff35}', '\u{ff55}'), ('\u{ff36}', '\u{ff56}'),
+    ('\u{ff37}', '\u{ff57}'), ('\u{ff38}', '\u{ff58}'), ('\u{ff39}', '\u{ff59}'),
+    ('\u{ff3a}', '\u{ff5a}'), ('\u{10400}', '\u{10428}'), ('\u{10401}', '\u{10429}'),
+    ('\u{10402}', '\u{1042a}'), ('\u{10403}', '\u{1042b}'), ('\u{10404}', '\u{1042c}'),
+    ('\u{10405}', '\u{1042d}'), ('\u{10406}', '\u{1042e}'), ('\u{10407}', '\u{1042f}'),
+    ('\u{10408}', '\u{10430}'), ('\u{10409}', '\u{10431}'), ('\u{1040a}', '\u{10432}'),
+    ('\u{1040b}', '\u{10433}'), ('\u{1040c}', '\u{10434}'), ('\u{1040d}', '\u{10435}'),
+    ('\u{1040e}', '\u{10436}'), ('\u{1040f}', '\u{10437}'), ('\u{10410}', '\u{10438}'),
+    ('\u{10411}', '\u{10439}'), ('\u{10412}', '\u{1043a}'), ('\u{10413}', '\u{1043b}'),
+    ('\u{10414}', '\u{1043c}'), ('\u{10415}', '\u{1043d}'), ('\u{10416}', '\u{1043e}'),
+    ('\u{10417}', '\u{1043f}'), ('\u{10418}', '\u{10440}'), ('\u{10419}', '\u{10441}'),
+    ('\u{1041a}', '\u{10442}'), ('\u{1041b}', '\u{10443}'), ('\u{1041c}', '\u{10444}'),
+    ('\u{1041d}', '\u{10445}'), ('\u{1041e}', '\u{10446}'), ('\u{1041f}', '\u{10447}'),
+    ('\u{10420}', '\u{10448}'), ('\u{10421}', '\u{10449}'), ('\u{10422}', '\u{1044a}'),
+    ('\u{10423}', '\u{1044b}'), ('\u{10424}', '\u{1044c}'), ('\u{10425}', '\u{1044d}'),
+    ('\u{10426}', '\u{1044e}'), ('\u{10427}', '\u{1044f}'), ('\u{104b0}', '\u{104d8}'),
+    ('\u{104b1}', '\u{104d9}'), ('\u{104b2}', '\u{104da}'), ('\u{104b3}', '\u{104db}'),
+    ('\u{104b4}', '\u{104dc}'), ('\u{104b5}', '\u{104dd}'), ('\u{104b6}', '\u{104de}'),
+    ('\u{104b7}', '\u{104df}'), ('\u{104b8}', '\u{104e0}'), ('\u{104b9}', '\u{104e1}'),
+    ('\u{104ba}', '\u{104e2}'), ('\u{104bb}', '\u{104e3}'), ('\u{104bc}', '\u{104e4}'),
+    ('\u{104bd}', '\u{104e5}'), ('\u{104be}', '\u{104e6}'), ('\u{104bf}', '\u{104e7}'),
+    ('\u{104c0}', '\u{104e8}'), ('\u{104c1}', '\u{104e9}'), ('\u{104c2}', '\u{104ea}'),
+    ('\u{104c3}', '\u{104eb}'), ('\u{104c4}', '\u{104ec}'), ('\u{104c5}', '\u{104ed}'),
+    ('\u{104c6}', '\u{104ee}'), ('\u{104c7}', '\u{104ef}'), ('\u{104c8}', '\u{104f0}'),
+    ('\u{104c9}', '\u{104f1}'), ('\u{104ca}', '\u{104f2}'), ('\u{104cb}', '\u{104f3}'),
+    ('\u{104cc}', '\u{104f4}'), ('\u{104cd}', '\u{104f5}'), ('\u{104ce}', '\u{104f6}'),
+    ('\u{104cf}', '\u{104f7}'), ('\u{104d0}', '\u{104f8}'), ('\u{104d1}', '\u{104f9}'),
+    ('\u{104d2}', '\u{104fa}'), ('\u{104d3}', '\u{104fb}'), ('\u{10570}', '\u{10597}'),
+    ('\u{10571}', '\u{10598}'), ('\u{10572}', '\u{10599}'), ('\u{10573}', '\u{1059a}'),
+    ('\u{10574}', '\u{1059b}'), ('\u{10575}', '\u{1059c}'), ('\u{10576}', '\u{1059d}'),
+    ('\u{10577}', '\u{1059e}'), ('\u{10578}', '\u{1059f}'), ('\u{10579}', '\u{105a0}'),
+    ('\u{1057a}', '\u{105a1}'), ('\u{1057c}', '\u{105a3}'), ('\u{1057d}', '\u{105a4}'),
+    ('\u{1057e}', '\u{105a5}'), ('\u{1057f}', '\u{105a6}'), ('\u{10580}', '\u{105a7}'),
+    ('\u{10581}', '\u{105a8}'), ('\u{10582}', '\u{105a9}'), ('\u{10583}', '\u{105aa}'),
+    ('\u{10584}', '\u{105ab}'), ('\u{10585}', '\u{105ac}'), ('\u{10586}', '\u{105ad}'),
+    ('\u{10587}', '\u{105ae}'), ('\u{10588}', '\u{105af}'), ('\u{10589}', '\u{105b0}'),
+    ('\u{1058a}', '\u{105b1}'), ('\u{1058c}', '\u{105b3}'), ('\u{1058d}', '\u{105b4}'),
+    ('\u{1058e}', '\u{105b5}'), ('\u{1058f}', '\u{105b6}'), ('\u{10590}', '\u{105b7}'),
+    ('\u{10591}', '\u{105b8}'), ('\u{10592}', '\u{105b9}'), ('\u{10594}', '\u{105bb}'),
+    ('\u{10595}', '\u{105bc}'), ('\u{10c80}', '\u{10cc0}'), ('\u{10c81}', '\u{10cc1}'),
+    ('\u{10c82}', '\u{10cc2}'), ('\u{10c83}', '\u{10cc3}'), ('\u{10c84}', '\u{10cc4}'),
+    ('\u{10c85}', '\u{10cc5}'), ('\u{10c86}', '\u{10cc6}'), ('\u{10c87}', '\u{10cc7}'),
+    ('\u{10c88}', '\u{10cc8}'), ('\u{10c89}', '\u{10cc9}'), ('\u{10c8a}', '\u{10cca}'),
+    ('\u{10c8b}', '\u{10ccb}'), ('\u{10c8c}', '\u{10ccc}'), ('\u{10c8d}', '\u{10ccd}'),
+    ('\u{10c8e}', '\u{10cce}'), ('\u{10c8f}', '\u{10ccf}'), ('\u{10c90}', '\u{10cd0}'),
+    ('\u{10c91}', '\u{10cd1}'), ('\u{10c92}', '\u{10cd2}'), ('\u{10c93}', '\u{10cd3}'),
+    ('\u{10c94}', '\u{10cd4}'), ('\u{10c95}', '\u{10cd5}'), ('\u{10c96}', '\u{10cd6}'),
+    ('\u{10c97}', '\u{10cd7}'), ('\u{10c98}', '\u{10cd8}'), ('\u{10c99}', '\u{10cd9}'),
+    ('\u{10c9a}', '\u{10cda}'), ('\u{10c9b}', '\u{10cdb}'), ('\u{10c9c}', '\u{10cdc}'),
+    ('\u{10c9d}', '\u{10cdd}'), ('\u{10c9e}', '\u{10cde}'), ('\u{10c9f}', '\u{10cdf}'),
+    ('\u{10ca0}', '\u{10ce0}'), ('\u{10ca1}', '\u{10ce1}'), ('\u{10ca2}', '\u{10ce2}'),
+    ('\u{10ca3}', '\u{10ce3}'), ('\u{10ca4}', '\u{10ce4}'), ('\u{10ca5}', '\u{10ce5}'),
+    ('\u{10ca6}', '\u{10ce6}'), ('\u{10ca7}', '\u{10ce7}'), ('\u{10ca8}', '\u{10ce8}'),
+    ('\u{10ca9}', '\u{10ce9}'), ('\u{10caa}', '\u{10cea}'), ('\u{10cab}', '\u{10ceb}'),
+    ('\u{10cac}', '\u{10cec}'), ('\u{10cad}', '\u{10ced}'), ('\u{10cae}', '\u{10cee}'),
+    ('\u{10caf}', '\u{10cef}'), ('\u{10cb0}', '\u{10cf0}'), ('\u{10cb1}', '\u{10cf1}'),
+    ('\u{10cb2}', '\u{10cf2}'), ('\u{118a0}', '\u{118c0}'), ('\u{118a1}', '\u{118c1}'),
+    ('\u{118a2}', '\u{118c2}'), ('\u{118a3}', '\u{118c3}'), ('\u{118a4}', '\u{118c4}'),
+    ('\u{118a5}', '\u{118c5}'), ('\u{118a6}', '\u{118c6}'), ('\u{118a7}', '\u{118c7}'),
+    ('\u{118a8}', '\u{118c8}'), ('\u{118a9}', '\u{118c9}'), ('\u{118aa}', '\u{118ca}'),
+    ('\u{118ab}', '\u{118cb}'), ('\u{118ac}', '\u{118cc}'), ('\u{118ad}', '\u{118cd}'),
+    ('\u{118ae}', '\u{118ce}'), ('\u{118af}', '\u{118cf}'), ('\u{118b0}', '\u{118d0}'),
+    ('\u{118b1}', '\u{118d1}'), ('\u{118b2}', '\u{118d2}'), ('\u{118b3}', '\u{118d3}'),
+    ('\u{118b4}', '\u{118d4}'), ('\u{118b5}', '\u{118d5}'), ('\u{118b6}', '\u{118d6}'),
+    ('\u{118b7}', '\u{118d7}'), ('\u{118b8}', '\u{118d8}'), ('\u{118b9}', '\u{118d9}'),
+    ('\u{118ba}', '\u{118da}'), ('\u{118bb}', '\u{118db}'), ('\u{118bc}', '\u{118dc}'),
+    ('\u{118bd}', '\u{118dd}'), ('\u{118be}', '\u{118de}'), ('\u{118bf}', '\u{118df}'),
+    ('\u{16e40}', '\u{16e60}'), ('\u{16e41}', '\u{16e61}'), ('\u{16e42}', '\u{16e62}'),
+    ('\u{16e43}', '\u{16e63}'), ('\u{16e44}', '\u{16e64}'), ('\u{16e45}', '\u{16e65}'),
+    ('\u{16e46}', '\u{16e66}'), ('\u{16e47}', '\u{16e67}'), ('\u{16e48}', '\u{16e68}'),
+    ('\u{16e49}', '\u{16e69}'), ('\u{16e4a}', '\u{16e6a}'), ('\u{16e4b}', '\u{16e6b}'),
+    ('\u{16e4c}', '\u{16e6c}'), ('\u{16e4d}', '\u{16e6d}'), ('\u{16e4e}', '\u{16e6e}'),
+    ('\u{16e4f}', '\u{16e6f}'), ('\u{16e50}', '\u{16e70}'), ('\u{16e51}', '\u{16e71}'),
+    ('\u{16e52}', '\u{16e72}'), ('\u{16e53}', '\u{16e73}'), ('\u{16e54}', '\u{16e74}'),
+    ('\u{16e55}', '\u{16e75}'), ('\u{16e56}', '\u{16e76}'), ('\u{16e57}', '\u{16e77}'),
+    ('\u{16e58}', '\u{16e78}'), ('\u{16e59}', '\u{16e79}'), ('\u{16e5a}', '\u{16e7a}'),
+    ('\u{16e5b}', '\u{16e7b}'), ('\u{16e5c}', '\u{16e7c}'), ('\u{16e5d}', '\u{16e7d}'),
+    ('\u{16e5e}', '\u{16e7e}'), ('\u{16e5f}', '\u{16e7f}'), ('\u{1e900}', '\u{1e922}'),
+    ('\u{1e901}', '\u{1e923}'), ('\u{1e902}', '\u{1e924}'), ('\u{1e903}', '\u{1e925}'),
+    ('\u{1e904}', '\u{1e926}'), ('\u{1e905}', '\u{1e927}'), ('\u{1e906}', '\u{1e928}'),
+    ('\u{1e907}', '\u{1e929}'), ('\u{1e908}', '\u{1e92a}'), ('\u{1e909}', '\u{1e92b}'),
+    ('\u{1e90a}', '\u{1e92c}'), ('\u{1e90b}', '\u{1e92d}'), ('\u{1e90c}', '\u{1e92e}'),
+    ('\u{1e90d}', '\u{1e92f}'), ('\u{1e90e}', '\u{1e930}'), ('\u{1e90f}', '\u{1e931}'),
+    ('\u{1e910}', '\u{1e932}'), ('\u{1e911}', '\u{1e933}'), ('\u{1e912}', '\u{1e934}'),
+    ('\u{1e913}', '\u{1e935}'), ('\u{1e914}', '\u{1e936}'), ('\u{1e915}', '\u{1e937}'),
+    ('\u{1e916}', '\u{1e938}'), ('\u{1e917}', '\u{1e939}'), ('\u{1e918}', '\u{1e93a}'),
+    ('\u{1e919}', '\u{1e93b}'), ('\u{1e91a}', '\u{1e93c}'), ('\u{1e91b}', '\u{1e93d}'),
+    ('\u{1e91c}', '\u{1e93e}'), ('\u{1e91d}', '\u{1e93f}'), ('\u{1e91e}', '\u{1e940}'),
+    ('\u{1e91f}', '\u{1e941}'), ('\u{1e920}', '\u{1e942}'), ('\u{1e921}', '\u{1e943}'),
+];
+
+/// Full (`F`) case foldings that map to more than one character.
+#[rustfmt::skip]
+static FULL_CASE_FOLDING: &[(char, &[char])] = &[
+    ('\u{df}', &['\u{73}', '\u{73}']), ('\u{130}', &['\u{69}', '\u{307}']),
+    ('\u{149}', &['\u{2bc}', '\u{6e}']), ('\u{1f0}', &['\u{6a}', '\u{30c}']),
+    ('\u{390}', &['\u{3b9}', '\u{308}', '\u{301}']),
+    ('\u{3b0}', &['\u{3c5}', '\u{308}', '\u{301}']), ('\u{587}', &['\u{565}', '\u{582}']),
+    ('\u{1e96}', &['\u{68}', '\u{331}']), ('\u{1e97}', &['\u{74}', '\u{308}']),
+    ('\u{1e98}', &['\u{77}', '\u{30a}']), ('\u{1e99}', &['\u{79}', '\u{30a}']),
+    ('\u{1e9a}', &['\u{61}', '\u{2be}']), ('\u{1e9e}', &['\u{73}', '\u{73}']),
+    ('\u{1f50}', &['\u{3c5}', '\u{313}']), ('\u{1f52}', &['\u{3c5}', '\u{313}', '\u{300}']),
+    ('\u{1f54}', &['\u{3c5}', '\u{313}', '\u{301}']),
+    ('\u{1f56}', &['\u{3c5}', '\u{313}', '\u{342}']), ('\u{1f80}', &['\u{1f00}', '\u{3b9}']),
+    ('\u{1f81}', &['\u{1f01}', '\u{3b9}']), ('\u{1f82}', &['\u{1f02}', '\u{3b9}']),
+    ('\u{1f83}', &['\u{1f03}', '\u{3b9}']), ('\u{1f84}', &['\u{1f04}', '\u{3b9}']),
+    ('\u{1f85}', &['\u{1f05}', '\u{3b9}']), ('\u{1f86}', &['\u{1f06}', '\u{3b9}']),
+    ('\u{1f87}', &['\u{1f07}', '\u{3b9}']), ('\u{1f88}', &['\u{1f00}', '\u{3b9}']),
+    ('\u{1f89}', &['\u{1f01}', '\u{3b9}']), ('\u{1f8a}', &['\u{1f02}', '\u{3b9}']),
+    ('\u{1f8b}', &['\u{1f03}', '\u{3b9}']), ('\u{1f8c}', &['\u{1f04}', '\u{3b9}']),
+    ('\u{1f8d}', &['\u{1f05}', '\u{3b9}']), ('\u{1f8e}', &['\u{1f06}', '\u{3b9}']),
+    ('\u{1f8f}', &['\u{1f07}', '\u{3b9}']), ('\u{1f90}', &['\u{1f20}', '\u{3b9}']),
+    ('\u{1f91}', &['\u{1f21}', '\u{3b9}']), ('\u{1f92}', &['\u{1f22}', '\u{3b9}']),
+    ('\u{1f93}', &['\u{1f23}', '\u{3b9}']), ('\u{1f94}', &['\u{1f24}', '\u{3b9}']),
+    ('\u{1f95}', &['\u{1f25}', '\u{3b9}']), ('\u{1f96}', &['\u{1f26}', '\u{3b9}']),
+    ('\u{1f97}', &['\u{1f27}', '\u{3b9}']), ('\u{1f98}', &['\u{1f20}', '\u{3b9}']),
+    ('\u{1f99}', &['\u{1f21}', '\u{3b9}']), ('\u{1f9a}', &['\u{1f22}', '\u{3b9}']),
+    ('\u{1f9b}', &['\u{1f23}', '\u{3b9}']), ('\u{1f9c}', &['\u{1f24}', '\u{3b9}']),
+    ('\u{1f9d}', &['\u{1f25}', '\u{3b9}']), ('\u{1f9e}', &['\u{1f26}', '\u{3b9}']),
+    ('\u{1f9f}', &['\u{1f27}', '\u{3b9}']), ('\u{1fa0}', &['\u{1f60}', '\u{3b9}']),
+    ('\u{1fa1}', &['\u{1f61}', '\u{3b9}']), ('\u{1fa2}', &['\u{1f62}', '\u{3b9}']),
+    ('\u{1fa3}', &['\u{1f63}', '\u{3b9}']), ('\u{1fa4}', &['\u{1f64}', '\u{3b9}']),
+    ('\u{1fa5}', &['\u{1f65}', '\u{3b9}']), ('\u{1fa6}', &['\u{1f66}', '\u{3b9}']),
+    ('\u{1fa7}', &['\u{1f67}', '\u{3b9}']), ('\u{1fa8}', &['\u{1f60}', '\u{3b9}']),
+    ('\u{1fa9}', &['\u{1f61}', '\u{3b9}']), ('\u{1faa}', &['\u{1f62}', '\u{3b9}']),
+    ('\u{1fab}', &['\u{1f63}', '\u{3b9}']), ('\u{1fac}', &['\u{1f64}', '\u{3b9}']),
+    ('\u{1fad}', &['\u{1f65}', '\u{3b9}']), ('\u{1fae}', &['\u{1f66}', '\u{3b9}']),
+    ('\u{1faf}', &['\u{1f67}', '\u{3b9}']), ('\u{1fb2}', &['\u{1f70}', '\u{3b9}']),
+    ('\u{1fb3}', &['\u{3b1}', '\u{3b9}']), ('\u{1fb4}', &['\u{3ac}', '\u{3b9}']),
+    ('\u{1fb6}', &['\u{3b1}', '\u{342}']), ('\u{1fb7}', &['\u{3b1}', '\u{342}', '\u{3b9}']),
+    ('\u{1fbc}', &['\u{3b1}', '\u{3b9}']), ('\u{1fc2}', &['\u{1f74}', '\u{3b9}']),
+    ('\u{1fc3}', &['\u{3b7}', '\u{3b9}']), ('\u{1fc4}', &['\u{3ae}', '\u{3b9}']),
+    ('\u{1fc6}', &['\u{3b7}', '\u{342}']), ('\u{1fc7}', &['\u{3b7}', '\u{342}', '\u{3b9}']),
+    ('\u{1fcc}', &['\u{3b7}', '\u{3b9}']), ('\u{1fd2}', &['\u{3b9}', '\u{308}', '\u{300}']),
+    ('\u{1fd3}', &['\u{3b9}', '\u{308}', '\u{301}']), ('\u{1fd6}', &['\u{3b9}', '\u{342}']),
+    ('\u{1fd7}', &['\u{3b9}', '\u{308}', '\u{342}']),
+    ('\u{1fe2}', &['\u{3c5}', '\u{308}', '\u{300}']),
+    ('\u{1fe3}', &['\u{3c5}', '\u{308}', '\u{301}']), ('\u{1fe4}', &['\u{3c1}', '\u{313}']),
+    ('\u{1fe6}', &['\u{3c5}', '\u{342}']), ('\u{1fe7}', &['\u{3c5}', '\u{308}', '\u{342}']),
+    ('\u{1ff2}', &['\u{1f7c}', '\u{3b9}']), ('\u{1ff3}', &['\u{3c9}', '\u{3b9}']),
+    ('\u{1ff4}', &['\u{3ce}', '\u{3b9}']), ('\u{1ff6}', &['\u{3c9}', '\u{342}']),
+    ('\u{1ff7}', &['\u{3c9}', '\u{342}', '\u{3b9}']), ('\u{1ffc}', &['\u{3c9}', '\u{3b9}']),
+    ('\u{fb00}', &['\u{66}', '\u{66}']), ('\u{fb01}', &['\u{66}', '\u{69}']),
+    ('\u{fb02}', &['\u{66}', '\u{6c}']), ('\u{fb03}', &['\u{66}', '\u{66}', '\u{69}']),
+    ('\u{fb04}', &['\u{66}', '\u{66}', '\u{6c}']), ('\u{fb05}', &['\u{73}', '\u{74}']),
+    ('\u{fb06}', &['\u{73}', '\u{74}']), ('\u{fb13}', &['\u{574}', '\u{576}']),
+    ('\u{fb14}', &['\u{574}', '\u{565}']), ('\u{fb15}', &['\u{574}', '\u{56b}']),
+    ('\u{fb16}', &['\u{57e}', '\u{576}']), ('\u{fb17}', &['\u{574}', '\u{56d}']),
+];
+
+/// Returns the simple upper case mapping of a UTF-16 code unit.
+///
+/// Unlike [`char::to_uppercase`], this never expands a character, so characters such as `ᾀ` map
+/// to their single character upper case (`ᾈ`) instead of being left unchanged. Code units that
+/// have no upper case mapping, including surrogates, are returned unchanged.
+#[inline]
+pub(crate) fn uppercase_simple_bmp(u: u16) -> u16 {
+    // Fast path: ASCII only maps lower case letters
+    if u < 0x80 {
+        return crate::ascii_uppercase(u);
+    }
+    SIMPLE_UPPERCASE_BMP
+        .binary_search_by_key(&u, |&(k, _)| k)
+        .map_or(u, |i| SIMPLE_UPPERCASE_BMP[i].1)
+}
+
+/// Simple upper case mappings of the Basic Multilingual Plane, other than ASCII.
+#[rustfmt::skip]
+static SIMPLE_UPPERCASE_BMP: &[(u16, u16)] = &[
+    (0xb5, 0x39c), (0xe0, 0xc0), (0xe1, 0xc1), (0xe2, 0xc2), (0xe3, 0xc3), (0xe4, 0xc4),
+    (0xe5, 0xc5), (0xe6, 0xc6), (0xe7, 0xc7), (0xe8, 0xc8), (0xe9, 0xc9), (0xea, 0xca),
+    (0xeb, 0xcb), (0xec, 0xcc), (0xed, 0xcd), (0xee, 0xce), (0xef, 0xcf), (0xf0, 0xd0),
+    (0xf1, 0xd1), (0xf2, 0xd2), (0xf3, 0xd3), (0xf4, 0xd4), (0xf5, 0xd5), (0xf6, 0xd6),
+    (0xf8, 0xd8), (0xf9, 0xd9), (0xfa, 0xda), (0xfb, 0xdb), (0xfc, 0xdc), (0xfd, 0xdd),
+    (0xfe, 0xde), (0xff, 0x178), (0x101, 0x100), (0x103, 0x102), (0x105, 0x104), (0x107, 0x106),
+    (0x109, 0x108), (0x10b, 0x10a), (0x10d, 0x10c), (0x10f, 0x10e), (0x111, 0x110), (0x113, 0x112),
+    (0x115, 0x114), (0x117, 0x116), (0x119, 0x118), (0x11b, 0x11a), (0x11d, 0x11c), (0x11f, 0x11e),
+    (0x121, 0x120), (0x123, 0x122), (0x125, 0x124), (0x127, 0x126), (0x129, 0x128), (0x12b, 0x12a),
+    (0x12d, 0x12c), (0x12f, 0x12e), (0x131, 0x49), (0x133, 0x132), (0x135, 0x134), (0x137, 0x136),
+    (0x13a, 0x139), (0x13c, 0x13b), (0x13e, 0x13d), (0x140, 0x13f), (0x142, 0x141), (0x144, 0x143),
+    (0x146, 0x145), (0x148, 0x147), (0x14b, 0x14a), (0x14d, 0x14c), (0x14f, 0x14e), (0x151, 0x150),
+    (0x153, 0x152), (0x155, 0x154), (0x157, 0x156), (0x159, 0x158), (0x15b, 0x15a), (0x15d, 0x15c),
+    (0x15f, 0x15e), (0x161, 0x160), (0x163, 0x162), (0x165, 0x164), (0x167, 0x166), (0x169, 0x168),
+    (0x16b, 0x16a), (0x16d, 0x16c), (0x16f, 0x16e), (0x171, 0x170), (0x173, 0x172), (0x175, 0x174),
+    (0x177, 0x176), (0x17a, 0x179), (0x17c, 0x17b), (0x17e, 0x17d), (0x17f, 0x53), (0x180, 0x243),
+    (0x183, 0x182), (0x185, 0x184), (0x188, 0x187), (0x18c, 0x18b), (0x192, 0x191), (0x195, 0x1f6),
+    (0x199, 0x198), (0x19a, 0x23d), (0x19e, 0x220), (0x1a1, 0x1a0), (0x1a3, 0x1a2), (0x1a5, 0x1a4),
+    (0x1a8, 0x1a7), (0x1ad, 0x1ac), (0x1b0, 0x1af), (0x1b4, 0x1b3), (0x1b6, 0x1b5), (0x1b9, 0x1b8),
+    (0x1bd, 0x1bc), (0x1bf, 0x1f7), (0x1c5, 0x1c4), (0x1c6, 0x1c4), (0x1c8, 0x1c7), (0x1c9, 0x1c7),
+    (0x1cb, 0x1ca), (0x1cc, 0x1ca), (0x1ce, 0x1cd), (0x1d0, 0x1cf), (0x1d2, 0x1d1), (0x1d4, 0x1d3),
+    (0x1d6, 0x1d5), (0x1d8, 0x1d7), (0x1da, 0x1d9), (0x1dc, 0x1db), (0x1dd, 0x18e), (0x1df, 0x1de),
+    (0x1e1, 0x1e0), (0x1e3, 0x1e2), (0x1e5, 0x1e4), (0x1e7, 0x1e6), (0x1e9, 0x1e8), (0x1eb, 0x1ea),
+    (0x1ed, 0x1ec), (0x1ef, 0x1ee), (0x1f2, 0x1f1), (0x1f3, 0x1f1), (0x1f5, 0x1f4), (0x1f9, 0x1f8),
+    (0x1fb, 0x1fa), (0x1fd, 0x1fc), (0x1ff, 0x1fe), (0x201, 0x200), (0x203, 0x202), (0x205, 0x204),
+    (0x207, 0x206), (0x209, 0x208), (0x20b, 0x20a), (0x20d, 0x20c), (0x20f, 0x20e), (0x211, 0x210),
+    (0x213, 0x212), (0x215, 0x214), (0x217, 0x216), (0x219, 0x218), (0x21b, 0x21a), (0x21d, 0x21c),
+    (0x21f, 0x21e), (0x223, 0x222), (0x225, 0x224), (0x227, 0x226), (0x229, 0x228), (0x22b, 0x22a),
+    (0x22d, 0x22c), (0x22f, 0x22e), (0x231, 0x230), (0x233, 0x232), (0x23c, 0x23b), (0x23f, 0x2c7e),
+    (0x240, 0x2c7f), (0x242, 0x241), (0x247, 0x246), (0x249, 0x248), (0x24b, 0x24a), (0x24d, 0x24c),
+    (0x24f, 0x24e), (0x250, 0x2c6f), (0x251, 0x2c6d), (0x252, 0x2c70), (0x253, 0x181),
+    (0x254, 0x186), (0x256, 0x189), (0x257, 0x18a), (0x259, 0x18f), (0x25b, 0x190), (0x25c, 0xa7ab),
+    (0x260, 0x193), (0x261, 0xa7ac), (0x263, 0x194), (0x265, 0xa78d), (0x266, 0xa7aa),
+    (0x268, 0x197), (0x269, 0x196), (0x26a, 0xa7ae), (0x26b, 0x2c62), (0x26c, 0xa7ad),
+    (0x26f, 0x19c), (0x271, 0x2c6e), (0x272, 0x19d), (0x275, 0x19f), (0x27d, 0x2c64),
+    (0x280, 0x1a6), (0x282, 0xa7c5), (0x283, 0x1a9), (0x287, 0xa7b1), (0x288, 0x1ae),
+    (0x289, 0x244), (0x28a, 0x1b1), (0x28b, 0x1b2), (0x28c, 0x245), (0x292, 0x1b7), (0x29d, 0xa7b2),
+    (0x29e, 0xa7b0), (0x345, 0x399), (0x371, 0x370), (0x373, 0x372), (0x377, 0x376), (0x37b, 0x3fd),
+    (0x37c, 0x3fe), (0x37d, 0x3ff), (0x3ac, 0x386), (0x3ad, 0x388), (0x3ae, 0x389), (0x3af, 0x38a),
+    (0x3b1, 0x391), (0x3b2, 0x392), (0x3b3, 0x393), (0x3b4, 0x394), (0x3b5, 0x395), (0x3b6, 0x396),
+    (0x3b7, 0x397), (0x3b8, 0x398), (0x3b9, 0x399), (0x3ba, 0x39a), (0x3bb, 0x39b), (0x3bc, 0x39c),
+    (0x3bd, 0x39d), (0x3be, 0x39e), (0x3bf, 0x39f), (0x3c0, 0x3a0), (0x3c1, 0x3a1), (0x3c2, 0x3a3),
+    (0x3c3, 0x3a3), (0x3c4, 0x3a4), (0x3c5, 0x3a5), (0x3c6, 0x3a6), (0x3c7, 0x3a7), (0x3c8, 0x3a8),
+    (0x3c9, 0x3a9), (0x3ca, 0x3aa), (0x3cb, 0x3ab), (0x3cc, 0x38c), (0x3cd, 0x38e), (0x3ce, 0x38f),
+    (0x3d0, 0x392), (0x3d1, 0x398), (0x3d5, 0x3a6), (0x3d6, 0x3a0), (0x3d7, 0x3cf), (0x3d9, 0x3d8),
+    (0x3db, 0x3da), (0x3dd, 0x3dc), (0x3df, 0x3de), (0x3e1, 0x3e0), (0x3e3, 0x3e2), (0x3e5, 0x3e4),
+    (0x3e7, 0x3e6), (0x3e9, 0x3e8), (0x3eb, 0x3ea), (0x3ed, 0x3ec), (0x3ef, 0x3ee), (0x3f0, 0x39a),
+    (0x3f1, 0x3a1), (0x3f2, 0x3f9), (0x3f3, 0x37f), (0x3f5, 0x395), (0x3f8, 0x3f7), (0x3fb, 0x3fa),
+    (0x430, 0x410), (0x431, 0x411), (0x432, 0x412), (0x433, 0x413), (0x434, 0x414), (0x435, 0x415),
+    (0x436, 0x416), (0x437, 0x417), (0x438, 0x418), (0x439, 0x419), (0x43a, 0x41a), (0x43b, 0x41b),
+    (0x43c, 0x41c), (0x43d, 0x41d), (0x43e, 0x41e), (0x43f, 0x41f), (0x440, 0x420), (0x441, 0x421),
+    (0x442, 0x422), (0x443, 0x423), (0x444, 0x424), (0x445, 0x425), (0x446, 0x426), (0x447, 0x427),
+    (0x448, 0x428), (0x449, 0x429), (0x44a, 0x42a), (0x44b, 0x42b), (0x44c, 0x42c), (0x44d, 0x42d),
+    (0x44e, 0x42e), (0x44f, 0x42f), (0x450, 0x400), (0x451, 0x401), (0x452, 0x402), (0x453, 0x403),
+    (0x454, 0x404), (0x455, 0x405), (0x456, 0x406), (0x457, 0x407), (0x458, 0x408), (0x459, 0x409),
+    (0x45a, 0x40a), (0x45b, 0x40b), (0x45c, 0x40c), (0x45d, 0x40d), (0x45e, 0x40e), (0x45f, 0x40f),
+    (0x461, 0x460), (0x463, 0x462), (0x465, 0x464), (0x467, 0x466), (0x469, 0x468), (0x46b, 0x46a),
+    (0x46d, 0x46c), (0x46f, 0x46e), (0x471, 0x470), (0x473, 0x472), (0x475, 0x474), (0x477, 0x476),
+    (0x479, 0x478), (0x47b, 0x47a), (0x47d, 0x47c), (0x47f, 0x47e), (0x481, 0x480), (0x48b, 0x48a),
+    (0x48d, 0x48c), (0x48f, 0x48e), (0x491, 0x490), (0x493, 0x492), (0x495, 0x494), (0x497, 0x496),
+    (0x499, 0x498), (0x49b, 0x49a), (0x49d, 0x49c), (0x49f, 0x49e), (0x4a1, 0x4a0), (0x4a3, 0x4a2),
+    (0x4a5, 0x4a4), (0x4a7, 0x4a6), (0x4a9, 0x4a8), (0x4ab, 0x4aa), (0x4ad, 0x4ac), (0x4af, 0x4ae),
+    (0x4b1, 0x4b0), (0x4b3, 0x4b2), (0x4b5, 0x4b4), (0x4b7, 0x4b6), (0x4b9, 0x4b8), (0x4bb, 0x4ba),
+    (0x4bd, 0x4bc), (0x4bf, 0x4be), (0x4c2, 0x4c1), (0x4c4, 0x4c3), (0x4c6, 0x4c5), (0x4c8, 0x4c7),
+    (0x4ca, 0x4c9), (0x4cc, 0x4cb), (0x4ce, 0x4cd), (0x4cf, 0x4c0), (0x4d1, 0x4d0), (0x4d3, 0x4d2),
+    (0x4d5, 0x4d4), (0x4d7, 0x4d6), (0x4d9, 0x4d8), (0x4db, 0x4da), (0x4dd, 0x4dc), (0x4df, 0x4de),
+    (0x4e1, 0x4e0), (0x4e3, 0x4e2), (0x4e5, 0x4e4), (0x4e7, 0x4e6), (0x4e9, 0x4e8), (0x4eb, 0x4ea),
+    (0x4ed, 0x4ec), (0x4ef, 0x4ee), (0x4f1, 0x4f0), (0x4f3, 0x4f2), (0x4f5, 0x4f4), (0x4f7, 0x4f6),
+    (0x4f9, 0x4f8), (0x4fb, 0x4fa), (0x4fd, 0x4fc), (0x4ff, 0x4fe), (0x501, 0x500), (0x503, 0x502),
+    (0x505, 0x504), (0x507, 0x506), (0x509, 0x508), (0x50b, 0x50a), (0x50d, 0x50c), (0x50f, 0x50e),
+    (0x511, 0x510), (0x513, 0x512), (0x515, 0x514), (0x517, 0x516), (0x519, 0x518), (0x51b, 0x51a),
+    (0x51d, 0x51c), (0x51f, 0x51e), (0x521, 0x520), (0x523, 0x522), (0x525, 0x524), (0x527, 0x526),
+    (0x529, 0x528), (0x52b, 0x52a), (0x52d, 0x52c), (0x52f, 0x52e), (0x561, 0x531), (0x562, 0x532),
+    (0x563, 0x533), (0x564, 0x534), (0x565, 0x535), (0x566, 0x536), (0x567, 0x537), (0x568, 0x538),
+    (0x569, 0x539), (0x56a, 0x53a), (0x56b, 0x53b), (0x56c, 0x53c), (0x56d, 0x53d), (0x56e, 0x53e),
+    (0x56f, 0x53f), (0x570, 0x540), (0x571, 0x541), (0x572, 0x542), (0x573, 0x543), (0x574, 0x544),
+    (0x575, 0x545), (0x576, 0x546), (0x577, 0x547), (0x578, 0x548), (0x579, 0x549), (0x57a, 0x54a),
+    (0x57b, 0x54b), (0x57c, 0x54c), (0x57d, 0x54d), (0x57e, 0x54e), (0x57f, 0x54f), (0x580, 0x550),
+    (0x581, 0x551), (0x582, 0x552), (0x583, 0x553), (0x584, 0x554), (0x585, 0x555), (0x586, 0x556),
+    (0x10d0, 0x1c90), (0x10d1, 0x1c91), (0x10d2, 0x1c92), (0x10d3, 0x1c93), (0x10d4, 0x1c94),
+    (0x10d5, 0x1c95), (0x10d6, 0x1c96), (0x10d7, 0x1c97), (0x10d8, 0x1c98), (0x10d9, 0x1c99),
+    (0x10da, 0x1c9a), (0x10db, 0x1c9b), (0x10dc, 0x1c9c), (0x10dd, 0x1c9d), (0x10de, 0x1c9e),
+    (0x10df, 0x1c9f), (0x10e0, 0x1ca0), (0x10e1, 0x1ca1), (0x10e2, 0x1ca2), (0x10e3, 0x1ca3),
+    (0x10e4, 0x1ca4), (0x10e5, 0x1ca5), (0x10e6, 0x1ca6), (0x10e7, 0x1ca7), (0x10e8, 0x1ca8),
+    (0x10e9, 0x1ca9), (0x10ea, 0x1caa), (0x10eb, 0x1cab), (0x10ec, 0x1cac), (0x10ed, 0x1cad),
+    (0x10ee, 0x1cae), (0x10ef, 0x1caf), (0x10f0, 0x1cb0), (0x10f1, 0x1cb1), (0x10f2, 0x1cb2),
+    (0x10f3, 0x1cb3), (0x10f4, 0x1cb4), (0x10f5, 0x1cb5), (0x10f6, 0x1cb6), (0x10f7, 0x1cb7),
+    (0x10f8, 0x1cb8), (0x10f9, 0x1cb9), (0x10fa, 0x1cba), (0x10fd, 0x1cbd), (0x10fe, 0x1cbe),
+    (0x10ff, 0x1cbf), (0x13f8, 0x13f0), (0x13f9, 0x13f1), (0x13fa, 0x13f2), (0x13fb, 0x13f3),
+    (0x13fc, 0x13f4), (0x13fd, 0x13f5), (0x1c80, 0x412), (0x1c81, 0x414), (0x1c82, 0x41e),
+    (0x1c83, 0x421), (0x1c84, 0x422), (0x1c85, 0x422), (0x1c86, 0x42a), (0x1c87, 0x462),
+    (0x1c88, 0xa64a), (0x1d79, 0xa77d), (0x1d7d, 0x2c63), (0x1d8e, 0xa7c6), (0x1e01, 0x1e00),
+    (0x1e03, 0x1e02), (0x1e05, 0x1e04), (0x1e07, 0x1e06), (0x1e09, 0x1e08), (0x1e0b, 0x1e0a),
+    (0x1e0d, 0x1e0c), (0x1e0f, 0x1e0e), (0x1e11, 0x1e10), (0x1e13, 0x1e12), (0x1e15, 0x1e14),
+    (0x1e17, 0x1e16), (0x1e19, 0x1e18), (0x1e1b, 0x1e1a), (0x1e1d, 0x1e1c), (0x1e1f, 0x1e1e),
+    (0x1e21, 0x1e20), (0x1e23, 0x1e22), (0x1e25, 0x1e24), (0x1e27, 0x1e26), (0x1e29, 0x1e28),
+    (0x1e2b, 0x1e2a), (0x1e2d, 0x1e2c), (0x1e2f, 0x1e2e), (0x1e31, 0x1e30), (0x1e33, 0x1e32),
+    (0x1e35, 0x1e34), (0x1e37, 0x1e36), (0x1e39, 0x1e38), (0x1e3b, 0x1e3a), (0x1e3d, 0x1e3c),
+    (0x1e3f, 0x1e3e), (0x1e41, 0x1e40), (0x1e43, 0x1e42), (0x1e45, 0x1e44), (0x1e47, 0x1e46),
+    (0x1e49, 0x1e48), (0x1e4b, 0x1e4a), (0x1e4d, 0x1e4c), (0x1e4f, 0x1e4e), (0x1e51, 0x1e50),
+    (0x1e53, 0x1e52), (0x1e55, 0x1e54), (0x1e57, 0x1e56), (0x1e59, 0x1e58), (0x1e5b, 0x1e5a),
+    (0x1e5d, 0x1e5c), (0x1e5f, 0x1e5e), (0x1e61, 0x1e60), (0x1e63, 0x1e62), (0x1e65, 0x1e64),
+    (0x1e67, 0x1e66), (0x1e69, 0x1e68), (0x1e6b, 0x1e6a), (0x1e6d, 0x1e6c), (0x1e6f, 0x1e6e),
+    (0x1e71, 0x1e70), (0x1e73, 0x1e72), (0x1e75, 0x1e74), (0x1e77, 0x1e76), (0x1e79, 0x1e78),
+    (0x1e7b, 0x1e7a), (0x1e7d, 0x1e7c), (0x1e7f, 0x1e7e), (0x1e81, 0x1e80), (0x1e83, 0x1e82),
+    (0x1e85, 0x1e84), (0x1e87, 0x1e86), (0x1e89, 0x1e88), (0x1e8b, 0x1e8a), (0x1e8d, 0x1e8c),
+    (0x1e8f, 0x1e8e), (0x1e91, 0x1e90), (0x1e93, 0x1e92), (0x1e95, 0x1e94), (0x1e9b, 0x1e60),
+    (0x1ea1, 0x1ea0), (0x1ea3, 0x1ea2), (0x1ea5, 0x1ea4), (0x1ea7, 0x1ea6), (0x1ea9, 0x1ea8),
+    (0x1eab, 0x1eaa), (0x1ead, 0x1eac), (0x1eaf, 0x1eae), (0x1eb1, 0x1eb0), (0x1eb3, 0x1eb2),
+    (0x1eb5, 0x1eb4), (0x1eb7, 0x1eb6), (0x1eb9, 0x1eb8), (0x1ebb, 0x1eba), (0x1ebd, 0x1ebc),
+    (0x1ebf, 0x1ebe), (0x1ec1, 0x1ec0), (0x1ec3, 0x1ec2), (0x1ec5, 0x1ec4), (0x1ec7, 0x1ec6),
+    (0x1ec9, 0x1ec8), (0x1ecb, 0x1eca), (0x1ecd, 0x1ecc), (0x1ecf, 0x1ece), (0x1ed1, 0x1ed0),
+    (0x1ed3, 0x1ed2), (0x1ed5, 0x1ed4), (0x1ed7, 0x1ed6), (0x1ed9, 0x1ed8), (0x1edb, 0x1eda),
+    (0x1edd, 0x1edc), (0x1edf, 0x1ede), (0x1ee1, 0x1ee0), (0x1ee3, 0x1ee2), (0x1ee5, 0x1ee4),
+    (0x1ee7, 0x1ee6), (0x1ee9, 0x1ee8), (0x1eeb, 0x1eea), (0x1eed, 0x1eec), (0x1eef, 0x1eee),
+    (0x1ef1, 0x1ef0), (0x1ef3, 0x1ef2), (0x1ef5, 0x1ef4), (0x1ef7, 0x1ef6), (0x1ef9, 0x1ef8),
+    (0x1efb, 0x1efa), (0x1efd, 0x1efc), (0x1eff, 0x1efe), (0x1f00, 0x1f08), (0x1f01, 0x1f09),
+    (0x1f02, 0x1f0a), (0x1f03, 0x1f0b), (0x1f04, 0x1f0c), (0x1f05, 0x1f0d), (0x1f06, 0x1f0e),
+    (0x1f07, 0x1f0f), (0x1f10, 0x1f18), (0x1f11, 0x1f19), (0x1f12, 0x1f1a), (0x1f13, 0x1f1b),
+    (0x1f14, 0x1f1c), (0x1f15, 0x1f1d), (0x1f20, 0x1f28), (0x1f21, 0x1f29), (0x1f22, 0x1f2a),
+    (0x1f23, 0x1f2b), (0x1f24, 0x1f2c), (0x1f25, 0x1f2d), (0x1f26, 0x1f2e), (0x1f27, 0x1f2f),
+    (0x1f30, 0x1f38), (0x1f31, 0x1f39), (0x1f32, 0x1f3a), (0x1f33, 0x1f3b), (0x1f34, 0x1f3c),
+    (0x1f35, 0x1f3d), (0x1f36, 0x1f3e), (0x1f37, 0x1f3f), (0x1f40, 0x1f48), (0x1f41, 0x1f49),
+    (0x1f42, 0x1f4a), (0x1f43, 0x1f4b), (0x1f44, 0x1f4c), (0x1f45, 0x1f4d), (0x1f51, 0x1f59),
+    (0x1f53, 0x1f5b), (0x1f55, 0x1f5d), (0x1f57, 0x1f5f), (0x1f60, 0x1f68), (0x1f61, 0x1f69),
+    (0x1f62, 0x1f6a), (0x1f63, 0x1f6b), (0x1f64, 0x1f6c), (0x1f65, 0x1f6d), (0x1f66, 0x1f6e),
+    (0x1f67, 0x1f6f), (0x1f70, 0x1fba), (0x1f71, 0x1fbb), (0x1f72, 0x1fc8), (0x1f73, 0x1fc9),
+    (0x1f74, 0x1fca), (0x1f75, 0x1fcb), (0x1f76, 0x1fda), (0x1f77, 0x1fdb), (0x1f78, 0x1ff8),
+    (0x1f79, 0x1ff9), (0x1f7a, 0x1fea), (0x1f7b, 0x1feb), (0x1f7c, 0x1ffa), (0x1f7d, 0x1ffb),
+    (0x1f80, 0x1f88), (0x1f81, 0x1f89), (0x1f82, 0x1f8a), (0x1f83, 0x1f8b), (0x1f84, 0x1f8c),
+    (0x1f85, 0x1f8d), (0x1f86, 0x1f8e), (0x1f87, 0x1f8f), (0x1f90, 0x1f98), (0x1f91, 0x1f99),
+    (0x1f92, 0x1f9a), (0x1f93, 0x1f9b), (0x1f94, 0x1f9c), (0x1f95, 0x1f9d), (0x1f96, 0x1f9e),
+    (0x1f97, 0x1f9f), (0x1fa0, 0x1fa8), (0x1fa1, 0x1fa9), (0x1fa2, 0x1faa), (0x1fa3, 0x1fab),
+    (0x1fa4, 0x1fac), (0x1fa5, 0x1fad), (0x1fa6, 0x1fae), (0x1fa7, 0x1faf), (0x1fb0, 0x1fb8),
+    (0x1fb1, 0x1fb9), (0x1fb3, 0x1fbc), (0x1fbe, 0x399), (0x1fc3, 0x1fcc), (0x1fd0, 0x1fd8),
+    (0x1fd1, 0x1fd9), (0x1fe0, 0x1fe8), (0x1fe1, 0x1fe9), (0x1fe5, 0x1fec), (0x1ff3, 0x1ffc),
+    (0x214e, 0x2132), (0x2170, 0x2160), (0x2171, 0x2161), (0x2172, 0x2162), (0x2173, 0x2163),
+    (0x2174, 0x2164), (0x2175, 0x2165), (0x2176, 0x2166), (0x2177, 0x2167), (0x2178, 0x2168),
+    (0x2179, 0x2169), (0x217a, 0x216a), (0x217b, 0x216b), (0x217c, 0x216c), (0x217d, 0x216d),
+    (0x217e, 0x216e), (0x217f, 0x216f), (0x2184, 0x2183), (0x24d0, 0x24b6), (0x24d1, 0x24b7),
+    (0x24d2, 0x24b8), (0x24d3, 0x24b9), (0x24d4, 0x24ba), (0x24d5, 0x24bb), (0x24d6, 0x24bc),
+    (0x24d7, 0x24bd), (0x24d8, 0x24be), (0x24d9, 0x24bf), (0x24da, 0x24c0), (0x24db, 0x24c1),
+    (0x24dc, 0x24c2), (0x24dd, 0x24c3), (0x24de, 0x24c4), (0x24df, 0x24c5), (0x24e0, 0x24c6),
+    (0x24e1, 0x24c7), (0x24e2, 0x24c8), (0x24e3, 0x24c9), (0x24e4, 0x24ca), (0x24e5, 0x24cb),
+    (0x24e6, 0x24cc), (0x24e7, 0x24cd), (0x24e8, 0x24ce), (0x24e9, 0x24cf), (0x2c30, 0x2c00),
+    (0x2c31, 0x2c01), (0x2c32, 0x2c02), (0x2c33, 0x2c03), (0x2c34, 0x2c04), (0x2c35, 0x2c05),
+    (0x2c36, 0x2c06), (0x2c37, 0x2c07), (0x2c38, 0x2c08), (0x2c39, 0x2c09), (0x2c3a, 0x2c0a),
+    (0x2c3b, 0x2c0b), (0x2c3c, 0x2c0c), (0x2c3d, 0x2c0d), (0x2c3e, 0x2c0e), (0x2c3f, 0x2c0f),
+    (0x2c40, 0x2c10), (0x2c41, 0x2c11), (0x2c42, 0x2c12), (0x2c43, 0x2c13), (0x2c44, 0x2c14),
+    (0x2c45, 0x2c15), (0x2c46, 0x2c16), (0x2c47, 0x2c17), (0x2c48, 0x2c18), (0x2c49, 0x2c19),
+    (0x2c4a, 0x2c1a), (0x2c4b, 0x2c1b), (0x2c4c, 0x2c1c), (0x2c4d, 0x2c1d), (0x2c4e, 0x2c1e),
+    (0x2c4f, 0x2c1f), (0x2c50, 0x2c20), (0x2c51, 0x2c21), (0x2c52, 0x2c22), (0x2c53, 0x2c23),
+    (0x2c54, 0x2c24), (0x2c55, 0x2c25), (0x2c56, 0x2c26), (0x2c57, 0x2c27), (0x2c58, 0x2c28),
+    (0x2c59, 0x2c29), (0x2c5a, 0x2c2a), (0x2c5b, 0x2c2b), (0x2c5c, 0x2c2c), (0x2c5d, 0x2c2d),
+    (0x2c5e, 0x2c2e), (0x2c5f, 0x2c2f), (0x2c61, 0x2c60), (0x2c65, 0x23a), (0x2c66, 0x23e),
+    (0x2c68, 0x2c67), (0x2c6a, 0x2c69), (0x2c6c, 0x2c6b), (0x2c73, 0x2c72), (0x2c76, 0x2c75),
+    (0x2c81, 0x2c80), (0x2c83, 0x2c82), (0x2c85, 0x2c84), (0x2c87, 0x2c86), (0x2c89, 0x2c88),
+    (0x2c8b, 0x2c8a), (0x2c8d, 0x2c8c), (0x2c8f, 0x2c8e), (0x2c91, 0x2c90), (0x2c93, 0x2c92),
+    (0x2c95, 0x2c94), (0x2c97, 0x2c96), (0x2c99, 0x2c98), (0x2c9b, 0x2c9a), (0x2c9d, 0x2c9c),
+    (0x2c9f, 0x2c9e), (0x2ca1, 0x2ca0), (0x2ca3, 0x2ca2), (0x2ca5, 0x2ca4), (0x2ca7, 0x2ca6),
+    (0x2ca9, 0x2ca8), (0x2cab, 0x2caa), (0x2cad, 0x2cac), (0x2caf, 0x2cae), (0x2cb1, 0x2cb0),
+    (0x2cb3, 0x2cb2), (0x2cb5, 0x2cb4), (0x2cb7, 0x2cb6), (0x2cb9, 0x2cb8), (0x2cbb, 0x2cba),
+    (0x2cbd, 0x2cbc), (0x2cbf, 0x2cbe), (0x2cc1, 0x2cc0), (0x2cc3, 0x2cc2), (0x2cc5, 0x2cc4),
+    (0x2cc7, 0x2cc6), (0x2cc9, 0x2cc8), (0x2ccb, 0x2cca), (0x2ccd, 0x2ccc), (0x2ccf, 0x2cce),
+    (0x2cd1, 0x2cd0), (0x2cd3, 0x2cd2), (0x2cd5, 0x2cd4), (0x2cd7, 0x2cd6), (0x2cd9, 0x2cd8),
+    (0x2cdb, 0x2cda), (0x2cdd, 0x2cdc), (0x2cdf, 0x2cde), (0x2ce1, 0x2ce0), (0x2ce3, 0x2ce2),
+    (0x2cec, 0x2ceb), (0x2cee, 0x2ced), (0x2cf3, 0x2cf2), (0x2d00, 0x10a0), (0x2d01, 0x10a1),
+    (0x2d02, 0x10a2), (0x2d03, 0x10a3), (0x2d04, 0x10a4), (0x2d05, 0x10a5), (0x2d06, 0x10a6),
+    (0x2d07, 0x10a7), (0x2d08, 0x10a8), (0x2d09, 0x10a9), (0x2d0a, 0x10aa), (0x2d0b, 0x10ab),
+    (0x2d0c, 0x10ac), (0x2d0d, 0x10ad), (0x2d0e, 0x10ae), (0x2d0f, 0x10af), (0x2d10, 0x10b0),
+    (0x2d11, 0x10b1), (0x2d12, 0x10b2), (0x2d13, 0x10b3), (0x2d14, 0x10b4), (0x2d15, 0x10b5),
+    (0x2d16, 0x10b6), (0x2d17, 0x10b7), (0x2d18, 0x10b8), (0x2d19, 0x10b9), (0x2d1a, 0x10ba),
+    (0x2d1b, 0x10bb), (0x2d1c, 0x10bc), (0x2d1d, 0x10bd), (0x2d1e, 0x10be), (0x2d1f, 0x10bf),
+    (0x2d20, 0x10c0), (0x2d21, 0x10c1), (0x2d22, 0x10c2), (0x2d23, 0x10c3), (0x2d24, 0x10c4),
+    (0x2d25, 0x10c5), (0x2d27, 0x10c7), (0x2d2d, 0x10cd), (0xa641, 0xa640), (0xa643, 0xa642),
+    (0xa645, 0xa644), (0xa647, 0xa646), (0xa649, 0xa648), (0xa64b, 0xa64a), (0xa64d, 0xa64c),
+    (0xa64f, 0xa64e), (0xa651, 0xa650), (0xa653, 0xa652), (0xa655, 0xa654), (0xa657, 0xa656),
+    (0xa659, 0xa658), (0xa65b, 0xa65a), (0xa65d, 0xa65c), (0xa65f, 0xa65e), (0xa661, 0xa660),
+    (0xa663, 0xa662), (0xa665, 0xa664), (0xa667, 0xa666), (0xa669, 0xa668), (0xa66b, 0xa66a),
+    (0xa66d, 0xa66c), (0xa681, 0xa680), (0xa683, 0xa682), (0xa685, 0xa684), (0xa687, 0xa686),
+    (0xa689, 0xa688), (0xa68b, 0xa68a), (0xa68d, 0xa68c), (0xa68f, 0xa68e), (0xa691, 0xa690),
+    (0xa693, 0xa692), (0xa695, 0xa694), (0xa697, 0xa696), (0xa699, 0xa698), (0xa69b, 0xa69a),
+    (0xa723, 0xa722), (0xa725, 0xa724), (0xa727, 0xa726), (0xa729, 0xa728), (0xa72b, 0xa72a),
+    (0xa72d, 0xa72c), (0xa72f, 0xa72e), (0xa733, 0xa732), (0xa735, 0xa734), (0xa737, 0xa736),
+    (0xa739, 0xa738), (0xa73b, 0xa73a), (0xa73d, 0xa73c), (0xa73f, 0xa73e), (0xa741, 0xa740),
+    (0xa743, 0xa742), (0xa745, 0xa744), (0xa747, 0xa746), (0xa749, 0xa748), (0xa74b, 0xa74a),
+    (0xa74d, 0xa74c), (0xa74f, 0xa74e), (0xa751, 0xa750), (0xa753, 0xa752), (0xa755, 0xa754),
+    (0xa757, 0xa756), (0xa759, 0xa758), (0xa75b, 0xa75a), (0xa75d, 0xa75c), (0xa75f, 0xa75e),
+    (0xa761, 0xa760), (0xa763, 0xa762), (0xa765, 0xa764), (0xa767, 0xa766), (0xa769, 0xa768),
+    (0xa76b, 0xa76a), (0xa76d, 0xa76c), (0xa76f, 0xa76e), (0xa77a, 0xa779), (0xa77c, 0xa77b),
+    (0xa77f, 0xa77e), (0xa781, 0xa780), (0xa783, 0xa782), (0xa785, 0xa784), (0xa787, 0xa786),
+    (0xa78c, 0xa78b), (0xa791, 0xa790), (0xa793, 0xa792), (0xa794, 0xa7c4), (0xa797, 0xa796),
+    (0xa799, 0xa798), (0xa79b, 0xa79a), (0xa79d, 0xa79c), (0xa79f, 0xa79e), (0xa7a1, 0xa7a0),
+    (0xa7a3, 0xa7a2), (0xa7a5, 0xa7a4), (0xa7a7, 0xa7a6), (0xa7a9, 0xa7a8), (0xa7b5, 0xa7b4),
+    (0xa7b7, 0xa7b6), (0xa7b9, 0xa7b8), (0xa7bb, 0xa7ba), (0xa7bd, 0xa7bc), (0xa7bf, 0xa7be),
+    (0xa7c1, 0xa7c0), (0xa7c3, 0xa7c2), (0xa7c8, 0xa7c7), (0xa7ca, 0xa7c9), (0xa7d1, 0xa7d0),
+    (0xa7d7, 0xa7d6), (0xa7d9, 0xa7d8), (0xa7f6, 0xa7f5), (0xab53, 0xa7b3), (0xab70, 0x13a0),
+    (0xab71, 0x13a1), (0xab72, 0x13a2), (0xab73, 0x13a3), (0xab74, 0x13a4), (0xab75, 0x13a5),
+    (0xab76, 0x13a6), (0xab77, 0x13a7), (0xab78, 0x13a8), (0xab79, 0x13a9), (0xab7a, 0x13aa),
+    (0xab7b, 0x13ab), (0xab7c, 0x13ac), (0xab7d, 0x13ad), (0xab7e, 0x13ae), (0xab7f, 0x13af),
+    (0xab80, 0x13b0), (0xab81, 0x13b1), (0xab82, 0x13b2), (0xab83, 0x13b3), (0xab84, 0x13b4),
+    (0xab85, 0x13b5), (0xab86, 0x13b6), (0xab87, 0x13b7), (0xab88, 0x13b8), (0xab89, 0x13b9),
+    (0xab8a, 0x13ba), (0xab8b, 0x13bb), (0xab8c, 0x13bc), (0xab8d, 0x13bd), (0xab8e, 0x13be),
+    (0xab8f, 0x13bf), (0xab90, 0x13c0), (0xab91, 0x13c1), (0xab92, 0x13c2), (0xab93, 0x13c3),
+    (0xab94, 0x13c4), (0xab95, 0x13c5), (0xab96, 0x13c6), (0xab97, 0x13c7), (0xab98, 0x13c8),
+    (0xab99, 0x13c9), (0xab9a, 0x13ca), (0xab9b, 0x13cb), (0xab9c, 0x13cc), (0xab9d, 0x13cd),
+    (0xab9e, 0x13ce), (0xab9f, 0x13cf), (0xaba0, 0x13d0), (0xaba1, 0x13d1), (0xaba2, 0x13d2),
+    (0xaba3, 0x13d3), (0xaba4, 0x13d4), (0xaba5, 0x13d5), (0xaba6, 0x13d6), (0xaba7, 0x13d7),
+    (0xaba8, 0x13d8), (0xaba9, 0x13d9), (0xabaa, 0x13da), (0xabab, 0x13db), (0xabac, 0x13dc),
+    (0xabad, 0x13dd), (0xabae, 0x13de), (0xabaf, 0x13df), (0xabb0, 0x13e0), (0xabb1, 0x13e1),
+    (0xabb2, 0x13e2), (0xabb3, 0x13e3), (0xabb4, 0x13e4), (0xabb5, 0x13e5), (0xabb6, 0x13e6),
+    (0xabb7, 0x13e7), (0xabb8, 0x13e8), (0xabb9, 0x13e9), (0xabba, 0x13ea), (0xabbb, 0x13eb),
+    (0xabbc, 0x13ec), (0xabbd, 0x13ed), (0xabbe, 0x13ee), (0xabbf, 0x13ef), (0xff41, 0xff21),
+    (0xff42, 0xff22), (0xff43, 0xff23), (0xff44, 0xff24), (0xff45, 0xff25), (0xff46, 0xff26),
+    (0xff47, 0xff27), (0xff48, 0xff28), (0xff49, 0xff29), (0xff4a, 0xff2a), (0xff4b, 0xff2b),
+    (0xff4c, 0xff2c), (0xff4d, 0xff2d), (0xff4e, 0xff2e), (0xff4f, 0xff2f), (0xff50, 0xff30),
+    (0xff51, 0xff31), (0xff52, 0xff32), (0xff53, 0xff33), (0xff54, 0xff34), (0xff55, 0xff35),
+    (0xff56, 0xff36), (0xff57, 0xff37), (0xff58, 0xff38), (0xff59, 0xff39), (0xff5a, 0xff3a),
+];
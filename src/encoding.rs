@@ -0,0 +1,998 @@
+//! Detection and decoding of byte-encoded Unicode text.
+//!
+//! This module contains functions to detect the Unicode encoding of text stored in bytes, such as
+//! the contents of a text file, using its byte order mark (BOM) or a heuristic when there is none,
+//! and to decode the text into a [`Utf16String`][crate::Utf16String],
+//! [`Utf32String`][crate::Utf32String], or [`String`][alloc::string::String].
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use widestring::{
+//!     encoding::{decode_to_utf16, Encoding, Fallback},
+//!     utf16str,
+//! };
+//!
+//! // UTF-16LE with a byte order mark
+//! let bytes = b"\xFF\xFEh\0i\0";
+//! let (s, encoding) = decode_to_utf16(bytes, Fallback::default()).unwrap();
+//! assert_eq!(s, utf16str!("hi"));
+//! assert_eq!(encoding, Encoding::Utf16Le);
+//!
+//! // UTF-16BE without a byte order mark is detected from its nul bytes
+//! let bytes = b"\0h\0i";
+//! let (s, encoding) = decode_to_utf16(bytes, Fallback::default()).unwrap();
+//! assert_eq!(s, utf16str!("hi"));
+//! assert_eq!(encoding, Encoding::Utf16Be);
+//! # }
+//! ```
+
+#[cfg(feature = "alloc")]
+use crate::{
+    decode_utf16, decode_utf32, error::DecodeBytesError, Utf16Str, Utf16String, Utf32String,
+};
+use crate::{
+    error::{DecodeUtf16Error, FromBytesError, InvalidByteLength, Utf16Error},
+    is_utf16_high_surrogate, is_utf16_low_surrogate,
+};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// A Unicode encoding form and byte order used to store text in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// UTF-8.
+    Utf8,
+    /// UTF-16 in little-endian byte order.
+    Utf16Le,
+    /// UTF-16 in big-endian byte order.
+    Utf16Be,
+    /// UTF-32 in little-endian byte order.
+    Utf32Le,
+    /// UTF-32 in big-endian byte order.
+    Utf32Be,
+}
+
+impl Encoding {
+    /// Detects an encoding from the byte order mark at the start of `bytes`.
+    ///
+    /// Returns the encoding and the length in bytes of the byte order mark, or [`None`] if `bytes`
+    /// does not start with a byte order mark. Since the UTF-32LE byte order mark starts with the
+    /// UTF-16LE byte order mark, UTF-16LE text starting with `U+0000` is detected as UTF-32LE.
+    #[must_use]
+    pub fn from_bom(bytes: &[u8]) -> Option<(Encoding, usize)> {
+        // Longest byte order marks first, as they share prefixes
+        [
+            Encoding::Utf32Le,
+            Encoding::Utf32Be,
+            Encoding::Utf8,
+            Encoding::Utf16Le,
+            Encoding::Utf16Be,
+        ]
+        .into_iter()
+        .find(|encoding| bytes.starts_with(encoding.bom()))
+        .map(|encoding| (encoding, encoding.bom().len()))
+    }
+
+    /// Returns the byte order mark of this encoding.
+    #[must_use]
+    pub const fn bom(self) -> &'static [u8] {
+        match self {
+            Encoding::Utf8 => &[0xEF, 0xBB, 0xBF],
+            Encoding::Utf16Le => &[0xFF, 0xFE],
+            Encoding::Utf16Be => &[0xFE, 0xFF],
+            Encoding::Utf32Le => &[0xFF, 0xFE, 0x00, 0x00],
+            Encoding::Utf32Be => &[0x00, 0x00, 0xFE, 0xFF],
+        }
+    }
+
+    /// Returns the size in bytes of the code units of this encoding.
+    #[must_use]
+    pub const fn unit_size(self) -> usize {
+        match self {
+            Encoding::Utf8 => 1,
+            Encoding::Utf16Le | Encoding::Utf16Be => 2,
+            Encoding::Utf32Le | Encoding::Utf32Be => 4,
+        }
+    }
+}
+
+/// The order of bytes within a code unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ByteOrder {
+    /// Little-endian byte order, with the least significant byte first.
+    Little,
+    /// Big-endian byte order, with the most significant byte first.
+    Big,
+}
+
+impl ByteOrder {
+    /// The native byte order of the target platform.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: ByteOrder = ByteOrder::Little;
+    /// The native byte order of the target platform.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: ByteOrder = ByteOrder::Big;
+}
+
+/// How to determine the encoding of bytes that do not start with a byte order mark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fallback {
+    /// Assume the bytes are in the given encoding.
+    Assume(Encoding),
+    /// Guess the encoding from the positions of nul bytes in the data, which are common in UTF-16
+    /// and UTF-32 encoded text that is mostly ASCII. If the guess is inconclusive, the data is
+    /// assumed to be UTF-8 if it is valid UTF-8, or the given encoding otherwise.
+    Guess(Encoding),
+}
+
+impl Default for Fallback {
+    /// Guesses the encoding, falling back to UTF-8.
+    #[inline]
+    fn default() -> Self {
+        Fallback::Guess(Encoding::Utf8)
+    }
+}
+
+/// The number of bytes examined when guessing an encoding.
+const GUESS_LIMIT: usize = 1024;
+
+/// Guesses the encoding of bytes without a byte order mark.
+fn guess(bytes: &[u8], default: Encoding) -> Encoding {
+    let sample = &bytes[..bytes.len().min(GUESS_LIMIT)];
+    if sample.len() >= 4 && sample.len() % 4 == 0 {
+        let units = sample.chunks_exact(4);
+        if units.clone().all(|u| u[2] == 0 && u[3] == 0) && units.clone().any(|u| u[0] != 0) {
+            return Encoding::Utf32Le;
+        }
+        if units.clone().all(|u| u[0] == 0 && u[1] == 0) && units.clone().any(|u| u[3] != 0) {
+            return Encoding::Utf32Be;
+        }
+    }
+    if sample.len() >= 2 {
+        let mut even_nuls = 0;
+        let mut odd_nuls = 0;
+        for unit in sample.chunks_exact(2) {
+            even_nuls += usize::from(unit[0] == 0);
+            odd_nuls += usize::from(unit[1] == 0);
+        }
+        // Require most units to be ASCII-like to avoid misdetecting binary data or CJK text
+        let units = sample.len() / 2;
+        if odd_nuls * 2 > units && even_nuls * 10 < units {
+            return Encoding::Utf16Le;
+        }
+        if even_nuls * 2 > units && odd_nuls * 10 < units {
+            return Encoding::Utf16Be;
+        }
+    }
+    if core::str::from_utf8(bytes).is_ok() {
+        Encoding::Utf8
+    } else {
+        default
+    }
+}
+
+/// Detects the encoding of bytes from their byte order mark, or from `fallback` if there is none.
+///
+/// Returns the encoding and the length in bytes of the byte order mark, which is `0` if there was
+/// none.
+#[must_use]
+pub fn detect_encoding(bytes: &[u8], fallback: Fallback) -> (Encoding, usize) {
+    match Encoding::from_bom(bytes) {
+        Some(detected) => detected,
+        None => match fallback {
+            Fallback::Assume(encoding) => (encoding, 0),
+            Fallback::Guess(default) => (guess(bytes, default), 0),
+        },
+    }
+}
+
+/// Decodes the chars of `bytes` in the given encoding, passing each to `push`.
+///
+/// When `lossy` is `true`, invalid data is replaced with
+/// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER] and no error is returned.
+#[cfg(feature = "alloc")]
+fn decode_chars(
+    bytes: &[u8],
+    encoding: Encoding,
+    lossy: bool,
+    mut push: impl FnMut(char),
+) -> Result<(), DecodeBytesError> {
+    let unit_size = encoding.unit_size();
+    let remainder = bytes.len() % unit_size;
+    if remainder != 0 && !lossy {
+        return Err(InvalidByteLength::new(bytes.len(), unit_size).into());
+    }
+    let units = bytes.chunks_exact(unit_size);
+    match encoding {
+        Encoding::Utf8 => {
+            if lossy {
+                String::from_utf8_lossy(bytes).chars().for_each(push);
+            } else {
+                core::str::from_utf8(bytes)?.chars().for_each(push);
+            }
+            return Ok(());
+        }
+        Encoding::Utf16Le | Encoding::Utf16Be => {
+            let from_bytes = if encoding == Encoding::Utf16Le {
+                u16::from_le_bytes
+            } else {
+                u16::from_be_bytes
+            };
+            for c in decode_utf16(units.map(|u| from_bytes([u[0], u[1]]))) {
+                match c {
+                    Ok(c) => push(c),
+                    Err(_) if lossy => push(char::REPLACEMENT_CHARACTER),
+                    Err(e) => return Err(e.into()),
+                }
+            }
+        }
+        Encoding::Utf32Le | Encoding::Utf32Be => {
+            let from_bytes = if encoding == Encoding::Utf32Le {
+                u32::from_le_bytes
+            } else {
+                u32::from_be_bytes
+            };
+            for c in decode_utf32(units.map(|u| from_bytes([u[0], u[1], u[2], u[3]]))) {
+                match c {
+                    Ok(c) => push(c),
+                    Err(_) if lossy => push(char::REPLACEMENT_CHARACTER),
+                    Err(e) => return Err(e.into()),
+                }
+            }
+        }
+    }
+    if remainder != 0 {
+        push(char::REPLACEMENT_CHARACTER);
+    }
+    Ok(())
+}
+
+macro_rules! decode_fns {
+    ($(
+        $(#[$meta:meta])* fn $name:ident, $(#[$lossy_meta:meta])* fn $lossy:ident -> $string:ty;
+    )+) => {$(
+        $(#[$meta])*
+        #[cfg(feature = "alloc")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        pub fn $name(
+            bytes: &[u8],
+            fallback: Fallback,
+        ) -> Result<($string, Encoding), DecodeBytesError> {
+            let (encoding, bom_len) = detect_encoding(bytes, fallback);
+            let bytes = &bytes[bom_len..];
+            let mut s = <$string>::with_capacity(bytes.len() / encoding.unit_size());
+            decode_chars(bytes, encoding, false, |c| s.push(c))?;
+            Ok((s, encoding))
+        }
+
+        $(#[$lossy_meta])*
+        #[cfg(feature = "alloc")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        #[must_use]
+        pub fn $lossy(bytes: &[u8], fallback: Fallback) -> ($string, Encoding) {
+            let (encoding, bom_len) = detect_encoding(bytes, fallback);
+            let bytes = &bytes[bom_len..];
+            let mut s = <$string>::with_capacity(bytes.len() / encoding.unit_size());
+            // Lossy decoding never returns an error
+            let _ = decode_chars(bytes, encoding, true, |c| s.push(c));
+            (s, encoding)
+        }
+    )+};
+}
+
+decode_fns! {
+    /// Detects the encoding of bytes and decodes them to a [`Utf16String`].
+    ///
+    /// The encoding is detected with [`detect_encoding`], and the byte order mark is not included
+    /// in the decoded string. Returns the decoded string and the detected encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not valid in the detected encoding.
+    fn decode_to_utf16,
+    /// Detects the encoding of bytes and decodes them to a [`Utf16String`], replacing invalid data
+    /// with [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER].
+    ///
+    /// The encoding is detected with [`detect_encoding`], and the byte order mark is not included
+    /// in the decoded string. Returns the decoded string and the detected encoding.
+    fn decode_to_utf16_lossy -> Utf16String;
+
+    /// Detects the encoding of bytes and decodes them to a [`Utf32String`].
+    ///
+    /// The encoding is detected with [`detect_encoding`], and the byte order mark is not included
+    /// in the decoded string. Returns the decoded string and the detected encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not valid in the detected encoding.
+    fn decode_to_utf32,
+    /// Detects the encoding of bytes and decodes them to a [`Utf32String`], replacing invalid data
+    /// with [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER].
+    ///
+    /// The encoding is detected with [`detect_encoding`], and the byte order mark is not included
+    /// in the decoded string. Returns the decoded string and the detected encoding.
+    fn decode_to_utf32_lossy -> Utf32String;
+
+    /// Detects the encoding of bytes and decodes them to a [`String`].
+    ///
+    /// The encoding is detected with [`detect_encoding`], and the byte order mark is not included
+    /// in the decoded string. Returns the decoded string and the detected encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not valid in the detected encoding.
+    fn decode_to_string,
+    /// Detects the encoding of bytes and decodes them to a [`String`], replacing invalid data with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER].
+    ///
+    /// The encoding is detected with [`detect_encoding`], and the byte order mark is not included
+    /// in the decoded string. Returns the decoded string and the detected encoding.
+    fn decode_to_string_lossy -> String;
+}
+
+/// A push-based decoder for UTF-16 text that arrives in chunks.
+///
+/// Unlike [`decode_utf16`][crate::decode_utf16], which decodes a single iterator, the decoder
+/// keeps its state between calls to [`decode`][Self::decode], so that a surrogate pair may be
+/// split across chunks. Errors report the absolute offset of the invalid code unit from the start
+/// of the stream.
+///
+/// # Examples
+///
+/// ```
+/// use widestring::encoding::Utf16Decoder;
+///
+/// let mut decoder = Utf16Decoder::new();
+/// let mut s = String::new();
+/// decoder.decode(&[0x61, 0xD83D], &mut s, false).unwrap();
+/// decoder.decode(&[0xDC96], &mut s, true).unwrap();
+/// assert_eq!(s, "a💖");
+///
+/// let mut decoder = Utf16Decoder::new();
+/// decoder.decode(&[0x61, 0x62], &mut s, false).unwrap();
+/// let error = decoder.decode(&[0xDC96], &mut s, true).unwrap_err();
+/// assert_eq!(error.index(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Utf16Decoder {
+    high: Option<u16>,
+    position: usize,
+}
+
+impl Utf16Decoder {
+    /// Creates a new decoder at the start of a stream.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of code units consumed by the decoder so far.
+    ///
+    /// This includes a trailing high surrogate that is held until the next chunk arrives.
+    #[inline]
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Decodes a chunk of code units, extending `out` with the decoded characters.
+    ///
+    /// A high surrogate at the end of `chunk` is held until the next call. `last` should be
+    /// `true` for the final chunk of the stream, in which case a held high surrogate is an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an unpaired surrogate is found, where [`Utf16Error::index`] is the
+    /// absolute offset of the surrogate in the stream. Decoding stops at the error, so code units
+    /// of `chunk` after it are not decoded. Use [`position`][Self::position] to find where to
+    /// resume decoding, or use [`decode_lossy`][Self::decode_lossy] instead.
+    pub fn decode<E: Extend<char>>(
+        &mut self,
+        chunk: &[u16],
+        out: &mut E,
+        last: bool,
+    ) -> Result<(), Utf16Error> {
+        self.decode_impl(chunk, out, last, false)
+    }
+
+    /// Decodes a chunk of code units, extending `out` with the decoded characters and replacing
+    /// unpaired surrogates with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER].
+    ///
+    /// A high surrogate at the end of `chunk` is held until the next call. `last` should be
+    /// `true` for the final chunk of the stream.
+    pub fn decode_lossy<E: Extend<char>>(&mut self, chunk: &[u16], out: &mut E, last: bool) {
+        // Lossy decoding never returns an error
+        let _ = self.decode_impl(chunk, out, last, true);
+    }
+
+    fn decode_impl<E: Extend<char>>(
+        &mut self,
+        chunk: &[u16],
+        out: &mut E,
+        last: bool,
+        lossy: bool,
+    ) -> Result<(), Utf16Error> {
+        // Absolute offset of chunk[0]; a held high surrogate is at `start - 1`
+        let start = self.position;
+        let mut high = self.high.take();
+        let mut i = 0;
+        let mut error = None;
+        let mut fail = |u: u16, index: usize| {
+            if lossy {
+                Some(char::REPLACEMENT_CHARACTER)
+            } else {
+                error = Some(Utf16Error::empty(index, DecodeUtf16Error::new(u)));
+                None
+            }
+        };
+        out.extend(core::iter::from_fn(|| loop {
+            let u = match chunk.get(i) {
+                Some(&u) => u,
+                None => {
+                    return match high.take() {
+                        Some(h) if last => fail(h, start + i - 1),
+                        h => {
+                            high = h;
+                            None
+                        }
+                    };
+                }
+            };
+            if let Some(h) = high.take() {
+                if is_utf16_low_surrogate(u) {
+                    i += 1;
+                    let c = (((h & 0x3FF) as u32) << 10 | (u & 0x3FF) as u32) + 0x1_0000;
+                    // SAFETY: Checked that it's a valid surrogate pair
+                    return Some(unsafe { char::from_u32_unchecked(c) });
+                }
+                return fail(h, start + i - 1);
+            }
+            i += 1;
+            if is_utf16_high_surrogate(u) {
+                high = Some(u);
+            } else if is_utf16_low_surrogate(u) {
+                return fail(u, start + i - 1);
+            } else {
+                // SAFETY: Not a surrogate
+                return Some(unsafe { char::from_u32_unchecked(u as u32) });
+            }
+        }));
+        self.position = start + i;
+        self.high = high;
+        match error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A push-based decoder for UTF-16 encoded bytes that arrive in chunks.
+///
+/// This is the same as [`Utf16Decoder`], but decodes bytes in the given [`ByteOrder`], so that
+/// both code units and surrogate pairs may be split across chunks.
+///
+/// # Examples
+///
+/// ```
+/// use widestring::encoding::{ByteOrder, Utf16ByteDecoder};
+///
+/// let mut decoder = Utf16ByteDecoder::new(ByteOrder::Little);
+/// let mut s = String::new();
+/// decoder.decode(b"a\0\x3D", &mut s, false).unwrap();
+/// decoder.decode(b"\xD8\x96\xDC", &mut s, true).unwrap();
+/// assert_eq!(s, "a💖");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Utf16ByteDecoder {
+    decoder: Utf16Decoder,
+    byte_order: ByteOrder,
+    pending: Option<u8>,
+}
+
+impl Utf16ByteDecoder {
+    /// Creates a new decoder at the start of a stream of bytes in the given byte order.
+    #[inline]
+    #[must_use]
+    pub fn new(byte_order: ByteOrder) -> Self {
+        Self {
+            decoder: Utf16Decoder::new(),
+            byte_order,
+            pending: None,
+        }
+    }
+
+    /// Returns the byte order used to decode the bytes.
+    #[inline]
+    #[must_use]
+    pub fn byte_order(&self) -> ByteOrder {
+        self.byte_order
+    }
+
+    /// Returns the number of bytes consumed by the decoder so far.
+    ///
+    /// This includes a trailing partial code unit or high surrogate that is held until the next
+    /// chunk arrives.
+    #[inline]
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.decoder.position() * 2 + usize::from(self.pending.is_some())
+    }
+
+    /// Decodes a chunk of bytes, extending `out` with the decoded characters.
+    ///
+    /// A partial code unit or high surrogate at the end of `chunk` is held until the next call.
+    /// `last` should be `true` for the final chunk of the stream, in which case anything held is
+    /// an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromBytesError::InvalidUtf`] if an unpaired surrogate is found, where
+    /// [`Utf16Error::index`] is the absolute offset of the surrogate in the stream, in code units.
+    /// Returns [`FromBytesError::InvalidByteLength`] if the stream ends with a partial code unit.
+    /// Decoding stops at the error, so bytes of `chunk` after it are not decoded.
+    pub fn decode<E: Extend<char>>(
+        &mut self,
+        chunk: &[u8],
+        out: &mut E,
+        last: bool,
+    ) -> Result<(), FromBytesError<Utf16Error>> {
+        self.decode_impl(chunk, out, last, false)
+    }
+
+    /// Decodes a chunk of bytes, extending `out` with the decoded characters and replacing
+    /// unpaired surrogates and a final partial code unit with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER].
+    ///
+    /// A partial code unit or high surrogate at the end of `chunk` is held until the next call.
+    /// `last` should be `true` for the final chunk of the stream.
+    pub fn decode_lossy<E: Extend<char>>(&mut self, chunk: &[u8], out: &mut E, last: bool) {
+        // Lossy decoding never returns an error
+        let _ = self.decode_impl(chunk, out, last, true);
+    }
+
+    fn decode_impl<E: Extend<char>>(
+        &mut self,
+        mut chunk: &[u8],
+        out: &mut E,
+        last: bool,
+        lossy: bool,
+    ) -> Result<(), FromBytesError<Utf16Error>> {
+        let from_bytes = match self.byte_order {
+            ByteOrder::Little => u16::from_le_bytes,
+            ByteOrder::Big => u16::from_be_bytes,
+        };
+        // Convert the bytes in batches to avoid allocating
+        let mut units = [0; 256];
+        let mut len = 0;
+        if let (Some(b), Some((&b2, rest))) = (self.pending, chunk.split_first()) {
+            units[0] = from_bytes([b, b2]);
+            len = 1;
+            chunk = rest;
+            self.pending = None;
+        }
+        let mut pairs = chunk.chunks_exact(2);
+        loop {
+            for (unit, bytes) in units[len..].iter_mut().zip(&mut pairs) {
+                *unit = from_bytes([bytes[0], bytes[1]]);
+                len += 1;
+            }
+            let done = pairs.len() == 0;
+            self.decoder
+                .decode_impl(&units[..len], out, last && done, lossy)?;
+            len = 0;
+            if done {
+                break;
+            }
+        }
+        if let [b] = pairs.remainder() {
+            self.pending = Some(*b);
+        }
+        if last && self.pending.take().is_some() {
+            if lossy {
+                out.extend(Some(char::REPLACEMENT_CHARACTER));
+            } else {
+                return Err(InvalidByteLength::new(self.position() + 1, 2).into());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The status of a [`Utf8ToUtf16Transcoder`] after a call to
+/// [`transcode`][Utf8ToUtf16Transcoder::transcode].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TranscodeStatus {
+    /// All input was consumed, and the transcoder is ready for the next chunk.
+    InputEmpty,
+    /// The output buffer is full. The call should be repeated with the unread input and more
+    /// space in the output buffer.
+    OutputFull,
+}
+
+/// A push-based transcoder from UTF-8 bytes that arrive in chunks to UTF-16 code units.
+///
+/// The transcoder holds a UTF-8 sequence split across chunks until the rest of it arrives, and
+/// writes into a caller-provided buffer, so it runs in bounded memory and does not require
+/// allocation. Invalid UTF-8 sequences are replaced with
+/// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER] the same way as
+/// [`String::from_utf8_lossy`][alloc::string::String::from_utf8_lossy].
+///
+/// # Examples
+///
+/// ```
+/// use widestring::encoding::{TranscodeStatus, Utf8ToUtf16Transcoder};
+///
+/// let mut transcoder = Utf8ToUtf16Transcoder::new();
+/// let mut output = [0; 4];
+/// let bytes = "a💖".as_bytes();
+///
+/// let (status, read, written) = transcoder.transcode(&bytes[..3], &mut output, false);
+/// assert_eq!((status, read, written), (TranscodeStatus::InputEmpty, 3, 1));
+/// let (status, read, written) = transcoder.transcode(&bytes[3..], &mut output[1..], true);
+/// assert_eq!((status, read, written), (TranscodeStatus::InputEmpty, 2, 2));
+/// assert_eq!(output[..3], [0x61, 0xD83D, 0xDC96]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Utf8ToUtf16Transcoder {
+    pending: [u8; 4],
+    pending_len: usize,
+}
+
+/// The first item decoded from the start of some UTF-8 bytes.
+enum Utf8Item {
+    /// A valid character and its length in bytes.
+    Char(char, usize),
+    /// An invalid sequence of the given length in bytes.
+    Invalid(usize),
+    /// A valid but incomplete sequence.
+    Incomplete,
+}
+
+impl Utf8Item {
+    fn decode(bytes: &[u8]) -> Self {
+        let bytes = &bytes[..bytes.len().min(4)];
+        let valid = match core::str::from_utf8(bytes) {
+            Ok(_) => bytes.len(),
+            Err(e) if e.valid_up_to() > 0 => e.valid_up_to(),
+            Err(e) => {
+                return e
+                    .error_len()
+                    .map_or(Utf8Item::Incomplete, Utf8Item::Invalid)
+            }
+        };
+        // SAFETY: Checked to be valid UTF-8 up to this point
+        let s = unsafe { core::str::from_utf8_unchecked(&bytes[..valid]) };
+        let c = s.chars().next().unwrap();
+        Utf8Item::Char(c, c.len_utf8())
+    }
+}
+
+impl Utf8ToUtf16Transcoder {
+    /// Creates a new transcoder at the start of a stream.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the transcoder holds an incomplete UTF-8 sequence from a previous chunk.
+    #[inline]
+    #[must_use]
+    pub fn has_pending(&self) -> bool {
+        self.pending_len > 0
+    }
+
+    /// Transcodes a chunk of UTF-8 bytes into UTF-16 code units in `output`.
+    ///
+    /// Returns the status of the transcoder, the number of bytes read from `input`, and the
+    /// number of code units written to `output`. An incomplete UTF-8 sequence at the end of
+    /// `input` is counted as read and held until the next call. `last` should be `true` for the
+    /// final chunk of the stream, in which case a held incomplete sequence is replaced with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER].
+    ///
+    /// A surrogate pair is never split across calls, so `output` always holds valid UTF-16 up to
+    /// the number of code units written. If the status is [`TranscodeStatus::OutputFull`], the
+    /// call should be repeated with the unread part of `input` and the same value of `last`.
+    pub fn transcode(
+        &mut self,
+        input: &[u8],
+        output: &mut [u16],
+        last: bool,
+    ) -> (TranscodeStatus, usize, usize) {
+        let mut read = 0;
+        let mut written = 0;
+        loop {
+            // Complete a sequence held from a previous chunk first
+            if self.pending_len > 0 {
+                let mut bytes = self.pending;
+                let extra = (input.len() - read).min(4 - self.pending_len);
+                let len = self.pending_len + extra;
+                bytes[self.pending_len..len].copy_from_slice(&input[read..read + extra]);
+                let (c, item_len) = match Utf8Item::decode(&bytes[..len]) {
+                    Utf8Item::Char(c, item_len) => (c, item_len),
+                    Utf8Item::Invalid(item_len) => (char::REPLACEMENT_CHARACTER, item_len),
+                    Utf8Item::Incomplete if last => (char::REPLACEMENT_CHARACTER, len),
+                    Utf8Item::Incomplete => {
+                        self.pending = bytes;
+                        self.pending_len = len;
+                        return (TranscodeStatus::InputEmpty, read + extra, written);
+                    }
+                };
+                if written + c.len_utf16() > output.len() {
+                    return (TranscodeStatus::OutputFull, read, written);
+                }
+                written += c.encode_utf16(&mut output[written..]).len();
+                read += item_len - self.pending_len;
+                self.pending_len = 0;
+            }
+
+            let rest = &input[read..];
+            if rest.is_empty() {
+                return (TranscodeStatus::InputEmpty, read, written);
+            }
+            // Only validate as much input as could fit in the output
+            let window = &rest[..rest.len().min((output.len() - written) * 3 + 4)];
+            let (valid, error) = match core::str::from_utf8(window) {
+                Ok(s) => (s, None),
+                Err(e) => {
+                    // SAFETY: Checked to be valid UTF-8 up to this point
+                    let s = unsafe { core::str::from_utf8_unchecked(&window[..e.valid_up_to()]) };
+                    (s, Some(e))
+                }
+            };
+            for c in valid.chars() {
+                if written + c.len_utf16() > output.len() {
+                    return (TranscodeStatus::OutputFull, read, written);
+                }
+                written += c.encode_utf16(&mut output[written..]).len();
+                read += c.len_utf8();
+            }
+            match error.map(|e| e.error_len()) {
+                Some(Some(len)) => {
+                    if written == output.len() {
+                        return (TranscodeStatus::OutputFull, read, written);
+                    }
+                    output[written] = char::REPLACEMENT_CHARACTER as u16;
+                    written += 1;
+                    read += len;
+                }
+                Some(None) if window.len() == rest.len() => {
+                    // Hold the incomplete sequence at the end of the chunk
+                    let tail = &input[read..];
+                    self.pending[..tail.len()].copy_from_slice(tail);
+                    self.pending_len = tail.len();
+                    read = input.len();
+                    if !last {
+                        return (TranscodeStatus::InputEmpty, read, written);
+                    }
+                }
+                // The window ended within a sequence, or there is more input past the window
+                _ => {}
+            }
+        }
+    }
+
+    /// Transcodes a chunk of UTF-8 bytes, appending the UTF-16 code units to `output`.
+    ///
+    /// This is the same as [`transcode`][Self::transcode], except that all of `input` is always
+    /// read.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn transcode_to_string(&mut self, mut input: &[u8], output: &mut Utf16String, last: bool) {
+        output.reserve(input.len());
+        let mut buf = [0; 256];
+        loop {
+            let (status, read, written) = self.transcode(input, &mut buf, last);
+            // SAFETY: The transcoder always writes valid UTF-16
+            output.push_utfstr(unsafe { Utf16Str::from_slice_unchecked(&buf[..written]) });
+            input = &input[read..];
+            if status == TranscodeStatus::InputEmpty {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn detect() {
+        let guess = Fallback::default();
+        assert_eq!(
+            detect_encoding(b"\xEF\xBB\xBFabc", guess),
+            (Encoding::Utf8, 3)
+        );
+        assert_eq!(
+            detect_encoding(b"\xFF\xFEa\0", guess),
+            (Encoding::Utf16Le, 2)
+        );
+        assert_eq!(
+            detect_encoding(b"\xFE\xFF\0a", guess),
+            (Encoding::Utf16Be, 2)
+        );
+        assert_eq!(
+            detect_encoding(b"\xFF\xFE\0\0a\0\0\0", guess),
+            (Encoding::Utf32Le, 4)
+        );
+        assert_eq!(
+            detect_encoding(b"\0\0\xFE\xFF\0\0\0a", guess),
+            (Encoding::Utf32Be, 4)
+        );
+
+        assert_eq!(detect_encoding(b"abcd", guess), (Encoding::Utf8, 0));
+        assert_eq!(detect_encoding(b"a\0b\0c\0", guess), (Encoding::Utf16Le, 0));
+        assert_eq!(detect_encoding(b"\0a\0b\0c", guess), (Encoding::Utf16Be, 0));
+        assert_eq!(
+            detect_encoding(b"a\0\0\0b\0\0\0", guess),
+            (Encoding::Utf32Le, 0)
+        );
+        assert_eq!(
+            detect_encoding(b"\0\0\0a\0\0\0b", guess),
+            (Encoding::Utf32Be, 0)
+        );
+        assert_eq!(
+            detect_encoding(b"\xFF\xFF", Fallback::Guess(Encoding::Utf16Be)),
+            (Encoding::Utf16Be, 0)
+        );
+        assert_eq!(
+            detect_encoding(b"a\0b\0", Fallback::Assume(Encoding::Utf8)),
+            (Encoding::Utf8, 0)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn decode() {
+        let guess = Fallback::default();
+        assert_eq!(
+            decode_to_utf32(b"\xFE\xFF\xD8\x3D\xDC\x96", guess).unwrap(),
+            (Utf32String::from_str("💖"), Encoding::Utf16Be)
+        );
+        assert_eq!(
+            decode_to_string(b"\xFF\xFE\0\0\x96\xF4\x01\0", guess).unwrap(),
+            (String::from("💖"), Encoding::Utf32Le)
+        );
+        assert_eq!(
+            decode_to_utf16(b"\xEF\xBB\xBF\xF0\x9F\x92\x96", guess).unwrap(),
+            (Utf16String::from_str("💖"), Encoding::Utf8)
+        );
+
+        assert!(matches!(
+            decode_to_utf16(b"\xFF\xFEa\0b", guess),
+            Err(DecodeBytesError::InvalidByteLength(_))
+        ));
+        assert!(matches!(
+            decode_to_utf16(b"\xFF\xFE\x00\xD8a\0", guess),
+            Err(DecodeBytesError::Utf16(_))
+        ));
+        assert!(matches!(
+            decode_to_utf16(b"\0\0\xFE\xFF\0\0\xD8\0", guess),
+            Err(DecodeBytesError::Utf32(_))
+        ));
+        assert!(matches!(
+            decode_to_utf16(b"\xEF\xBB\xBF\xFF", guess),
+            Err(DecodeBytesError::Utf8(_))
+        ));
+        assert_eq!(
+            decode_to_string_lossy(b"\xFF\xFE\x00\xD8a\0b", guess),
+            (String::from("\u{FFFD}a\u{FFFD}"), Encoding::Utf16Le)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf16_decoder() {
+        use alloc::vec::Vec;
+
+        let mut decoder = Utf16Decoder::new();
+        let mut chars = Vec::new();
+        decoder.decode(&[0x61, 0xD83D], &mut chars, false).unwrap();
+        decoder.decode(&[], &mut chars, false).unwrap();
+        decoder
+            .decode(&[0xDC96, 0xD83D], &mut chars, false)
+            .unwrap();
+        let error = decoder.decode(&[0x62], &mut chars, true).unwrap_err();
+        assert_eq!(error.index(), 3);
+        assert_eq!(decoder.position(), 4);
+        decoder
+            .decode(&[0x62, 0xD800], &mut chars, true)
+            .unwrap_err();
+        assert_eq!(chars, ['a', '💖', 'b']);
+
+        let mut decoder = Utf16Decoder::new();
+        let mut chars = Vec::new();
+        decoder.decode_lossy(&[0xDC00, 0xD800], &mut chars, false);
+        decoder.decode_lossy(&[0xD800], &mut chars, true);
+        assert_eq!(chars, ['\u{FFFD}'; 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf16_byte_decoder() {
+        use alloc::vec::Vec;
+
+        let mut decoder = Utf16ByteDecoder::new(ByteOrder::Big);
+        let mut chars = Vec::new();
+        for b in b"\0a\xD8\x3D\xDC\x96" {
+            decoder.decode(&[*b], &mut chars, false).unwrap();
+        }
+        let long = [0, 0x62].repeat(300);
+        decoder.decode(&long, &mut chars, false).unwrap();
+        assert_eq!(decoder.position(), 606);
+        assert!(matches!(
+            decoder.decode(b"\0", &mut chars, true),
+            Err(FromBytesError::InvalidByteLength(e)) if e.byte_len() == 607
+        ));
+        assert_eq!(chars.len(), 302);
+        assert_eq!(chars[..2], ['a', '💖']);
+
+        let mut decoder = Utf16ByteDecoder::new(ByteOrder::Little);
+        let mut chars = Vec::new();
+        decoder.decode_lossy(b"a\0\x00\xDCb", &mut chars, true);
+        assert_eq!(chars, ['a', '\u{FFFD}', '\u{FFFD}']);
+    }
+
+    #[test]
+    fn utf8_to_utf16_transcoder() {
+        let input = "a💖\u{7FF}".as_bytes();
+        let mut transcoder = Utf8ToUtf16Transcoder::new();
+        let mut output = [0; 8];
+        let mut written = 0;
+        for (i, b) in input.iter().enumerate() {
+            let (status, read, n) =
+                transcoder.transcode(&[*b], &mut output[written..], i == input.len() - 1);
+            assert_eq!((status, read), (TranscodeStatus::InputEmpty, 1));
+            written += n;
+        }
+        assert_eq!(output[..written], [0x61, 0xD83D, 0xDC96, 0x7FF]);
+
+        // Output too small for a surrogate pair
+        let mut transcoder = Utf8ToUtf16Transcoder::new();
+        assert_eq!(
+            transcoder.transcode(input, &mut output[..2], true),
+            (TranscodeStatus::OutputFull, 1, 1)
+        );
+        assert_eq!(
+            transcoder.transcode(&input[1..], &mut output[..0], true),
+            (TranscodeStatus::OutputFull, 0, 0)
+        );
+
+        // Invalid and incomplete sequences
+        let mut transcoder = Utf8ToUtf16Transcoder::new();
+        assert_eq!(
+            transcoder.transcode(b"\xFFa\xF0\x9F", &mut output, false),
+            (TranscodeStatus::InputEmpty, 4, 2)
+        );
+        assert!(transcoder.has_pending());
+        assert_eq!(
+            transcoder.transcode(b"", &mut output[2..2], true),
+            (TranscodeStatus::OutputFull, 0, 0)
+        );
+        assert_eq!(
+            transcoder.transcode(b"", &mut output[2..], true),
+            (TranscodeStatus::InputEmpty, 0, 1)
+        );
+        assert_eq!(output[..3], [0xFFFD, 0x61, 0xFFFD]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf8_to_utf16_transcoder_string() {
+        let input = "a💖".repeat(200);
+        let mut transcoder = Utf8ToUtf16Transcoder::new();
+        let mut s = Utf16String::new();
+        for chunk in input.as_bytes().chunks(7) {
+            transcoder.transcode_to_string(chunk, &mut s, false);
+        }
+        transcoder.transcode_to_string(b"\xE2\x82", &mut s, true);
+        assert_eq!(s.len(), 601);
+        assert_eq!(s.chars().next_back(), Some('\u{FFFD}'));
+    }
+}
@@ -355,3 +355,291 @@ impl std::error::Error for Utf32Error {
         Some(&self.source)
     }
 }
+
+/// An error returned to indicate that a byte slice could not be split into whole code units,
+/// because its length was not a multiple of the code unit size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidByteLength {
+    len: usize,
+    unit_size: usize,
+}
+
+impl InvalidByteLength {
+    pub(crate) fn new(len: usize, unit_size: usize) -> Self {
+        Self { len, unit_size }
+    }
+
+    /// Returns the length in bytes of the invalid byte slice.
+    #[must_use]
+    pub fn byte_len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the size in bytes of the code units the byte slice was being converted to.
+    #[must_use]
+    pub fn unit_size(&self) -> usize {
+        self.unit_size
+    }
+}
+
+impl core::fmt::Display for InvalidByteLength {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "byte length {} is not a multiple of the code unit size {}",
+            self.len, self.unit_size
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidByteLength {}
+
+/// An error returned to indicate a problem converting bytes to a UTF string.
+///
+/// The error will either be an [`InvalidByteLength`] if the bytes could not be split into code
+/// units, or the UTF error `E` if the code units were not valid for the encoding.
+#[derive(Debug, Clone)]
+pub enum FromBytesError<E> {
+    /// The byte length was not a multiple of the code unit size.
+    InvalidByteLength(InvalidByteLength),
+    /// The code units were not valid for the encoding.
+    InvalidUtf(E),
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for FromBytesError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidByteLength(e) => e.fmt(f),
+            Self::InvalidUtf(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for FromBytesError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidByteLength(e) => Some(e),
+            Self::InvalidUtf(e) => Some(e),
+        }
+    }
+}
+
+impl<E> From<InvalidByteLength> for FromBytesError<E> {
+    #[inline]
+    fn from(value: InvalidByteLength) -> Self {
+        Self::InvalidByteLength(value)
+    }
+}
+
+impl From<Utf16Error> for FromBytesError<Utf16Error> {
+    #[inline]
+    fn from(value: Utf16Error) -> Self {
+        Self::InvalidUtf(value)
+    }
+}
+
+impl From<Utf32Error> for FromBytesError<Utf32Error> {
+    #[inline]
+    fn from(value: Utf32Error) -> Self {
+        Self::InvalidUtf(value)
+    }
+}
+
+/// An error returned to indicate a problem decoding bytes in a detected encoding.
+///
+/// See [`encoding`][crate::encoding] for the functions that return this error.
+#[derive(Debug, Clone)]
+pub enum DecodeBytesError {
+    /// The byte length was not a multiple of the code unit size of the encoding.
+    InvalidByteLength(InvalidByteLength),
+    /// The bytes were not valid UTF-8.
+    Utf8(core::str::Utf8Error),
+    /// The bytes contained an unpaired UTF-16 surrogate.
+    Utf16(DecodeUtf16Error),
+    /// The bytes contained an invalid UTF-32 code point.
+    Utf32(DecodeUtf32Error),
+}
+
+impl core::fmt::Display for DecodeBytesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidByteLength(e) => e.fmt(f),
+            Self::Utf8(e) => e.fmt(f),
+            Self::Utf16(e) => e.fmt(f),
+            Self::Utf32(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeBytesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidByteLength(e) => Some(e),
+            Self::Utf8(e) => Some(e),
+            Self::Utf16(e) => Some(e),
+            Self::Utf32(e) => Some(e),
+        }
+    }
+}
+
+impl From<InvalidByteLength> for DecodeBytesError {
+    #[inline]
+    fn from(value: InvalidByteLength) -> Self {
+        Self::InvalidByteLength(value)
+    }
+}
+
+impl From<core::str::Utf8Error> for DecodeBytesError {
+    #[inline]
+    fn from(value: core::str::Utf8Error) -> Self {
+        Self::Utf8(value)
+    }
+}
+
+impl From<DecodeUtf16Error> for DecodeBytesError {
+    #[inline]
+    fn from(value: DecodeUtf16Error) -> Self {
+        Self::Utf16(value)
+    }
+}
+
+impl From<DecodeUtf32Error> for DecodeBytesError {
+    #[inline]
+    fn from(value: DecodeUtf32Error) -> Self {
+        Self::Utf32(value)
+    }
+}
+
+/// An error returned to indicate that bytes are not valid WTF-8.
+///
+/// See [`Wtf8Str::from_bytes`][crate::Wtf8Str::from_bytes] for more.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wtf8Error {
+    valid_up_to: usize,
+}
+
+impl Wtf8Error {
+    pub(crate) fn new(valid_up_to: usize) -> Self {
+        Self { valid_up_to }
+    }
+
+    /// Returns the index in the given bytes up to which valid WTF-8 was verified.
+    #[must_use]
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+
+impl core::fmt::Display for Wtf8Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid WTF-8 sequence at index {}", self.valid_up_to)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Wtf8Error {}
+
+/// An error returned to indicate that bytes are not valid UTF-8.
+///
+/// This is the same as [`core::str::Utf8Error`], but is returned when decoding UTF-8 bytes
+/// directly to a wide string, such as with
+/// [`Utf16String::from_utf8`][crate::Utf16String::from_utf8].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utf8Error {
+    valid_up_to: usize,
+    error_len: Option<u8>,
+}
+
+impl Utf8Error {
+    #[cfg(feature = "alloc")]
+    pub(crate) fn new(valid_up_to: usize, error_len: Option<usize>) -> Self {
+        Self {
+            valid_up_to,
+            error_len: error_len.map(|len| len as u8),
+        }
+    }
+
+    /// Returns the index in the given bytes up to which valid UTF-8 was verified.
+    #[must_use]
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// Returns the length of the invalid byte sequence at [`valid_up_to`][Self::valid_up_to], or
+    /// [`None`] if the bytes ended with an incomplete sequence.
+    ///
+    /// This is the same as [`core::str::Utf8Error::error_len`].
+    #[must_use]
+    pub fn error_len(&self) -> Option<usize> {
+        self.error_len.map(|len| len as usize)
+    }
+}
+
+impl core::fmt::Display for Utf8Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.error_len {
+            Some(len) => write!(
+                f,
+                "invalid utf-8 sequence of {} bytes from index {}",
+                len, self.valid_up_to
+            ),
+            None => write!(
+                f,
+                "incomplete utf-8 byte sequence from index {}",
+                self.valid_up_to
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Utf8Error {}
+
+impl From<core::str::Utf8Error> for Utf8Error {
+    #[inline]
+    fn from(value: core::str::Utf8Error) -> Self {
+        Self {
+            valid_up_to: value.valid_up_to(),
+            error_len: value.error_len().map(|len| len as u8),
+        }
+    }
+}
+
+/// An error returned to indicate that a buffer was too small to hold the result of a conversion.
+///
+/// The error reports the size the buffer needs to be for the conversion to succeed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    required: usize,
+}
+
+impl BufferTooSmall {
+    pub(crate) fn new(required: usize) -> Self {
+        Self { required }
+    }
+
+    /// Returns the number of elements the buffer needs to hold the result of the conversion.
+    #[must_use]
+    pub fn required(&self) -> usize {
+        self.required
+    }
+}
+
+impl core::fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "buffer too small, {} elements are required",
+            self.required
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
@@ -0,0 +1,740 @@
+//! Readers and writers for byte streams of UTF-16 and UTF-32 encoded text.
+//!
+//! [`Utf16Reader`] and [`Utf32Reader`] wrap any [`Read`] implementation and decode its bytes in a
+//! chosen [`ByteOrder`] incrementally, so that large files or streams never have to be fully
+//! loaded into memory. Text can be read one [`char`] at a time, by line, or in chunks of
+//! whatever is currently buffered.
+//!
+//! [`Utf16Writer`] and [`Utf32Writer`] wrap any [`Write`] implementation and encode text written
+//! to them with [`fmt::Write`] or as UTF-8 bytes with [`io::Write`][Write].
+//!
+//! # Examples
+//!
+//! ```
+//! use std::io::Cursor;
+//! use widestring::{encoding::ByteOrder, io::Utf16Reader, utf16str, Utf16String};
+//!
+//! let bytes = b"h\0i\0\n\0\x3D\xD8\x96\xDC";
+//! let mut reader = Utf16Reader::new(Cursor::new(bytes), ByteOrder::Little);
+//!
+//! let mut line = Utf16String::new();
+//! reader.read_line(&mut line)?;
+//! assert_eq!(line, utf16str!("hi\n"));
+//! assert_eq!(reader.read_char()?, Some('💖'));
+//! assert_eq!(reader.read_char()?, None);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! ```
+//! use std::fmt::Write;
+//! use widestring::{
+//!     encoding::ByteOrder,
+//!     io::{Newline, Utf16Writer},
+//! };
+//!
+//! let mut writer = Utf16Writer::new(Vec::new(), ByteOrder::Little).newline(Newline::CrLf);
+//! writer.write_bom()?;
+//! write!(writer, "{}\n", 42).unwrap();
+//! assert_eq!(writer.into_inner(), b"\xFF\xFE4\x002\x00\r\x00\n\x00");
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use crate::{
+    encode_utf16, encode_utf32,
+    encoding::ByteOrder,
+    error::{DecodeUtf16Error, DecodeUtf32Error},
+    is_utf16_high_surrogate, is_utf16_low_surrogate, Utf16String, Utf32String,
+};
+use std::{
+    fmt,
+    io::{self, Read, Write},
+    vec::Vec,
+};
+
+const DEFAULT_BUF_SIZE: usize = 8 * 1024;
+
+macro_rules! reader_common_impl {
+    {
+        $(#[$reader_meta:meta])*
+        struct $reader:ident([$uchar:ty; $size:literal]) -> $utfstring:ident;
+    } => {
+        $(#[$reader_meta])*
+        pub struct $reader<R> {
+            inner: R,
+            byte_order: ByteOrder,
+            buf: Box<[u8]>,
+            pos: usize,
+            filled: usize,
+        }
+
+        impl<R: Read> $reader<R> {
+            /// Creates a new reader that decodes the bytes of `inner` in the given byte order, with
+            /// a default buffer capacity.
+            #[must_use]
+            pub fn new(inner: R, byte_order: ByteOrder) -> Self {
+                Self::with_capacity(DEFAULT_BUF_SIZE, inner, byte_order)
+            }
+
+            /// Creates a new reader that decodes the bytes of `inner` in the given byte order, with
+            /// a buffer of at least `capacity` bytes.
+            #[must_use]
+            pub fn with_capacity(capacity: usize, inner: R, byte_order: ByteOrder) -> Self {
+                Self {
+                    inner,
+                    byte_order,
+                    buf: vec![0; capacity.max(2 * $size)].into_boxed_slice(),
+                    pos: 0,
+                    filled: 0,
+                }
+            }
+
+            /// Reads the next [`char`] from the stream, or returns [`None`] at the end of the
+            /// stream.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error of kind [`InvalidData`][io::ErrorKind::InvalidData] if the stream
+            /// contains invalid data, which is skipped so that reading can continue afterwards, or
+            /// of kind [`UnexpectedEof`][io::ErrorKind::UnexpectedEof] if the stream ends in the
+            /// middle of a code unit, in which case the partial code unit is discarded. Any error
+            /// from the underlying reader is also returned.
+            pub fn read_char(&mut self) -> io::Result<Option<char>> {
+                if self.fill(Self::MAX_CHAR_UNITS)? == 0 {
+                    return Ok(None);
+                }
+                self.decode_buffered(true)
+            }
+
+            /// Reads all characters until a newline (`\n`) is reached and appends them, including
+            /// the newline, to `buf`.
+            ///
+            /// Returns the number of code units appended, which is `0` at the end of the stream.
+            ///
+            /// # Errors
+            ///
+            /// Returns the same errors as [`read_char`][Self::read_char]. Any characters read
+            /// before the error are still appended to `buf`.
+            pub fn read_line(&mut self, buf: &mut $utfstring) -> io::Result<usize> {
+                let start = buf.len();
+                while let Some(c) = self.read_char()? {
+                    buf.push(c);
+                    if c == '\n' {
+                        break;
+                    }
+                }
+                Ok(buf.len() - start)
+            }
+
+            /// Reads the characters currently available from the stream and appends them to
+            /// `buf`, reading from the underlying reader only if nothing is buffered.
+            ///
+            /// Returns the number of code units appended, which is `0` at the end of the stream.
+            /// A surrogate pair split across reads of the underlying reader is never split.
+            ///
+            /// # Errors
+            ///
+            /// Returns the same errors as [`read_char`][Self::read_char]. Any characters read
+            /// before the error are still appended to `buf`.
+            pub fn read_chunk(&mut self, buf: &mut $utfstring) -> io::Result<usize> {
+                if self.fill(1)? == 0 {
+                    return Ok(0);
+                }
+                let start = buf.len();
+                while let Some(c) = self.decode_buffered(false)? {
+                    buf.push(c);
+                }
+                if buf.len() == start {
+                    // Only part of a character was buffered
+                    if let Some(c) = self.read_char()? {
+                        buf.push(c);
+                    }
+                }
+                Ok(buf.len() - start)
+            }
+
+            /// Reads all characters until the end of the stream and appends them to `buf`.
+            ///
+            /// Returns the number of code units appended.
+            ///
+            /// # Errors
+            ///
+            /// Returns the same errors as [`read_char`][Self::read_char]. Any characters read
+            /// before the error are still appended to `buf`.
+            pub fn read_to_string(&mut self, buf: &mut $utfstring) -> io::Result<usize> {
+                let start = buf.len();
+                while self.read_chunk(buf)? != 0 {}
+                Ok(buf.len() - start)
+            }
+
+            /// Fills the buffer until at least `units` whole code units are buffered or the end of
+            /// the stream is reached, returning the number of whole code units buffered.
+            fn fill(&mut self, units: usize) -> io::Result<usize> {
+                let needed = units * $size;
+                if self.filled - self.pos < needed {
+                    self.buf.copy_within(self.pos..self.filled, 0);
+                    self.filled -= self.pos;
+                    self.pos = 0;
+                    while self.filled < needed {
+                        match self.inner.read(&mut self.buf[self.filled..]) {
+                            Ok(0) => break,
+                            Ok(n) => self.filled += n,
+                            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                            Err(e) => return Err(e),
+                        }
+                    }
+                    if self.filled > 0 && self.filled < $size {
+                        // Drop the partial unit so later reads see the end of the stream
+                        self.filled = 0;
+                        return Err(io::Error::new(
+                            io::ErrorKind::UnexpectedEof,
+                            "stream ended in the middle of a code unit",
+                        ));
+                    }
+                }
+                Ok((self.filled - self.pos) / $size)
+            }
+
+            /// Returns the buffered code unit at `index` whole units from the current position.
+            fn unit(&self, index: usize) -> $uchar {
+                let start = self.pos + index * $size;
+                let mut bytes = [0; $size];
+                bytes.copy_from_slice(&self.buf[start..start + $size]);
+                match self.byte_order {
+                    ByteOrder::Little => <$uchar>::from_le_bytes(bytes),
+                    ByteOrder::Big => <$uchar>::from_be_bytes(bytes),
+                }
+            }
+
+            /// Returns the number of whole code units buffered.
+            fn buffered_units(&self) -> usize {
+                (self.filled - self.pos) / $size
+            }
+
+            fn consume(&mut self, units: usize) {
+                self.pos += units * $size;
+            }
+        }
+
+        impl<R> $reader<R> {
+            /// Gets a reference to the underlying reader.
+            #[inline]
+            #[must_use]
+            pub fn get_ref(&self) -> &R {
+                &self.inner
+            }
+
+            /// Gets a mutable reference to the underlying reader.
+            ///
+            /// Reading directly from the underlying reader will skip data that would otherwise
+            /// have been decoded.
+            #[inline]
+            #[must_use]
+            pub fn get_mut(&mut self) -> &mut R {
+                &mut self.inner
+            }
+
+            /// Consumes this reader, returning the underlying reader.
+            ///
+            /// Any buffered data that has not been decoded yet is lost.
+            #[inline]
+            #[must_use]
+            pub fn into_inner(self) -> R {
+                self.inner
+            }
+
+            /// Returns the byte order used to decode the stream.
+            #[inline]
+            #[must_use]
+            pub fn byte_order(&self) -> ByteOrder {
+                self.byte_order
+            }
+        }
+
+        impl<R: Read> Iterator for $reader<R> {
+            type Item = io::Result<char>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.read_char().transpose()
+            }
+        }
+
+        impl<R: fmt::Debug> fmt::Debug for $reader<R> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($reader))
+                    .field("inner", &self.inner)
+                    .field("byte_order", &self.byte_order)
+                    .field(
+                        "buffer",
+                        &format_args!("{}/{}", self.filled - self.pos, self.buf.len()),
+                    )
+                    .finish()
+            }
+        }
+    };
+}
+
+reader_common_impl! {
+    /// A reader that decodes a byte stream of UTF-16 encoded text.
+    ///
+    /// The reader buffers the underlying [`Read`] implementation, so wrapping it in a
+    /// [`BufReader`][std::io::BufReader] is not necessary. Surrogate pairs split across reads of
+    /// the underlying reader are decoded correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use widestring::{encoding::ByteOrder, io::Utf16Reader, utf16str, Utf16String};
+    ///
+    /// let bytes = b"\0h\0e\0l\0l\0o";
+    /// let mut reader = Utf16Reader::new(Cursor::new(bytes), ByteOrder::Big);
+    ///
+    /// let mut s = Utf16String::new();
+    /// reader.read_to_string(&mut s)?;
+    /// assert_eq!(s, utf16str!("hello"));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    struct Utf16Reader([u16; 2]) -> Utf16String;
+}
+
+reader_common_impl! {
+    /// A reader that decodes a byte stream of UTF-32 encoded text.
+    ///
+    /// The reader buffers the underlying [`Read`] implementation, so wrapping it in a
+    /// [`BufReader`][std::io::BufReader] is not necessary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use widestring::{encoding::ByteOrder, io::Utf32Reader};
+    ///
+    /// let bytes = b"h\0\0\0i\0\0\0";
+    /// let reader = Utf32Reader::new(Cursor::new(bytes), ByteOrder::Little);
+    ///
+    /// let chars: Result<Vec<char>, _> = reader.collect();
+    /// assert_eq!(chars?, ['h', 'i']);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    struct Utf32Reader([u32; 4]) -> Utf32String;
+}
+
+impl<R: Read> Utf16Reader<R> {
+    /// The maximum number of code units in a single character.
+    const MAX_CHAR_UNITS: usize = 2;
+
+    /// Decodes the next character from the buffered code units. Returns [`None`] if no whole
+    /// character is buffered, unless `complete` is `true` and a high surrogate is all that remains.
+    fn decode_buffered(&mut self, complete: bool) -> io::Result<Option<char>> {
+        let units = self.buffered_units();
+        if units == 0 {
+            return Ok(None);
+        }
+        let u = self.unit(0);
+        if is_utf16_low_surrogate(u) {
+            self.consume(1);
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                DecodeUtf16Error::new(u),
+            ));
+        }
+        if !is_utf16_high_surrogate(u) {
+            self.consume(1);
+            // SAFETY: Not a surrogate
+            return Ok(Some(unsafe { char::from_u32_unchecked(u as u32) }));
+        }
+        if units == 1 && !complete {
+            return Ok(None);
+        }
+        let u2 = if units > 1 { self.unit(1) } else { 0 };
+        if !is_utf16_low_surrogate(u2) {
+            self.consume(1);
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                DecodeUtf16Error::new(u),
+            ));
+        }
+        self.consume(2);
+        let c = (((u & 0x3FF) as u32) << 10 | (u2 & 0x3FF) as u32) + 0x1_0000;
+        // SAFETY: Checked that it's a valid surrogate pair
+        Ok(Some(unsafe { char::from_u32_unchecked(c) }))
+    }
+}
+
+impl<R: Read> Utf32Reader<R> {
+    /// The maximum number of code units in a single character.
+    const MAX_CHAR_UNITS: usize = 1;
+
+    /// Decodes the next character from the buffered code units. Returns [`None`] if no whole
+    /// character is buffered.
+    fn decode_buffered(&mut self, _complete: bool) -> io::Result<Option<char>> {
+        if self.buffered_units() == 0 {
+            return Ok(None);
+        }
+        let u = self.unit(0);
+        self.consume(1);
+        match char::from_u32(u) {
+            Some(c) => Ok(Some(c)),
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                DecodeUtf32Error::new(u),
+            )),
+        }
+    }
+}
+
+/// How a writer translates newlines in the text written to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Newline {
+    /// Newlines are written unchanged.
+    Unchanged,
+    /// Line feeds (`\n`) that are not already preceded by a carriage return (`\r`) are written as
+    /// a carriage return and line feed (`\r\n`), as expected by many Windows tools.
+    CrLf,
+}
+
+impl Default for Newline {
+    #[inline]
+    fn default() -> Self {
+        Newline::Unchanged
+    }
+}
+
+macro_rules! writer_common_impl {
+    {
+        $(#[$writer_meta:meta])*
+        struct $writer:ident([$uchar:ty; $size:literal]) => $encode:ident;
+    } => {
+        $(#[$writer_meta])*
+        #[derive(Debug)]
+        pub struct $writer<W> {
+            inner: W,
+            byte_order: ByteOrder,
+            newline: Newline,
+            last_cr: bool,
+            pending: [u8; 4],
+            pending_len: usize,
+        }
+
+        impl<W: Write> $writer<W> {
+            /// Creates a new writer that encodes text to `inner` in the given byte order.
+            ///
+            /// No byte order mark is written unless [`write_bom`][Self::write_bom] is called, and
+            /// newlines are written unchanged unless set otherwise with
+            /// [`newline`][Self::newline].
+            #[must_use]
+            pub fn new(inner: W, byte_order: ByteOrder) -> Self {
+                Self {
+                    inner,
+                    byte_order,
+                    newline: Newline::Unchanged,
+                    last_cr: false,
+                    pending: [0; 4],
+                    pending_len: 0,
+                }
+            }
+
+            /// Sets how newlines are translated in the text written after this call.
+            #[must_use]
+            pub fn newline(mut self, newline: Newline) -> Self {
+                self.newline = newline;
+                self
+            }
+
+            /// Writes a byte order mark to the underlying writer.
+            ///
+            /// This should be called before any text is written.
+            ///
+            /// # Errors
+            ///
+            /// Returns any error from the underlying writer.
+            pub fn write_bom(&mut self) -> io::Result<()> {
+                self.write_encoded("\u{FEFF}")
+            }
+
+            /// Encodes `s` and writes it to the underlying writer, translating newlines.
+            fn write_encoded(&mut self, s: &str) -> io::Result<()> {
+                let crlf = self.newline == Newline::CrLf;
+                let mut last_cr = self.last_cr;
+                let chars = s.chars().flat_map(|c| {
+                    let cr = (crlf && c == '\n' && !last_cr).then(|| '\r');
+                    last_cr = c == '\r';
+                    cr.into_iter().chain(Some(c))
+                });
+                let mut bytes = Vec::with_capacity(s.len() * $size);
+                for unit in $encode(chars) {
+                    bytes.extend_from_slice(&match self.byte_order {
+                        ByteOrder::Little => unit.to_le_bytes(),
+                        ByteOrder::Big => unit.to_be_bytes(),
+                    });
+                }
+                self.last_cr = s.chars().next_back().map_or(self.last_cr, |c| c == '\r');
+                self.inner.write_all(&bytes)
+            }
+        }
+
+        impl<W> $writer<W> {
+            /// Gets a reference to the underlying writer.
+            #[inline]
+            #[must_use]
+            pub fn get_ref(&self) -> &W {
+                &self.inner
+            }
+
+            /// Gets a mutable reference to the underlying writer.
+            #[inline]
+            #[must_use]
+            pub fn get_mut(&mut self) -> &mut W {
+                &mut self.inner
+            }
+
+            /// Consumes this writer, returning the underlying writer.
+            ///
+            /// Any incomplete UTF-8 sequence written with [`io::Write`][Write] is lost.
+            #[inline]
+            #[must_use]
+            pub fn into_inner(self) -> W {
+                self.inner
+            }
+
+            /// Returns the byte order used to encode the text.
+            #[inline]
+            #[must_use]
+            pub fn byte_order(&self) -> ByteOrder {
+                self.byte_order
+            }
+        }
+
+        impl<W: Write> fmt::Write for $writer<W> {
+            #[inline]
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.write_encoded(s).map_err(|_| fmt::Error)
+            }
+        }
+
+        impl<W: Write> Write for $writer<W> {
+            /// Writes UTF-8 encoded bytes, which are encoded again to the underlying writer.
+            ///
+            /// A UTF-8 sequence may be split across multiple writes. Returns an error of kind
+            /// [`InvalidData`][io::ErrorKind::InvalidData] if `buf` is not valid UTF-8.
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                let mut consumed = 0;
+                if self.pending_len > 0 {
+                    // Complete the sequence left over from the previous write
+                    let mut bytes = self.pending;
+                    let extra = buf.len().min(4 - self.pending_len);
+                    let len = self.pending_len + extra;
+                    bytes[self.pending_len..len].copy_from_slice(&buf[..extra]);
+                    let valid = match core::str::from_utf8(&bytes[..len]) {
+                        Ok(_) => len,
+                        Err(e) if e.valid_up_to() > 0 => e.valid_up_to(),
+                        Err(e) if e.error_len().is_none() => {
+                            self.pending = bytes;
+                            self.pending_len = len;
+                            return Ok(extra);
+                        }
+                        Err(e) => {
+                            self.pending_len = 0;
+                            return Err(io::Error::new(io::ErrorKind::InvalidData, e));
+                        }
+                    };
+                    // SAFETY: Checked to be valid UTF-8 above
+                    let s = unsafe { core::str::from_utf8_unchecked(&bytes[..valid]) };
+                    self.write_encoded(s)?;
+                    consumed = valid - self.pending_len;
+                    self.pending_len = 0;
+                }
+                let buf = &buf[consumed..];
+                match core::str::from_utf8(buf) {
+                    Ok(s) => {
+                        self.write_encoded(s)?;
+                        Ok(consumed + buf.len())
+                    }
+                    Err(e) => {
+                        let valid = e.valid_up_to();
+                        // SAFETY: Checked to be valid UTF-8 up to this point
+                        let s = unsafe { core::str::from_utf8_unchecked(&buf[..valid]) };
+                        self.write_encoded(s)?;
+                        if e.error_len().is_none() {
+                            // Keep the incomplete sequence at the end for the next write
+                            let rest = &buf[valid..];
+                            self.pending[..rest.len()].copy_from_slice(rest);
+                            self.pending_len = rest.len();
+                            Ok(consumed + buf.len())
+                        } else if consumed + valid == 0 {
+                            Err(io::Error::new(io::ErrorKind::InvalidData, e))
+                        } else {
+                            // Report the error on the next write
+                            Ok(consumed + valid)
+                        }
+                    }
+                }
+            }
+
+            #[inline]
+            fn flush(&mut self) -> io::Result<()> {
+                self.inner.flush()
+            }
+        }
+    };
+}
+
+writer_common_impl! {
+    /// A writer that encodes text to a byte stream of UTF-16 encoded text.
+    ///
+    /// Text can be written with [`fmt::Write`], such as with the [`write!`] macro, or as UTF-8
+    /// bytes with [`io::Write`][Write]. Since both traits provide a `write_fmt` method, only one
+    /// of them should be imported where [`write!`] is used. Each write is passed directly to the
+    /// underlying writer, so wrapping it in a [`BufWriter`][std::io::BufWriter] is recommended
+    /// when writing many small pieces of text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    /// use widestring::{encoding::ByteOrder, io::Utf16Writer};
+    ///
+    /// let mut writer = Utf16Writer::new(Vec::new(), ByteOrder::Big);
+    /// writer.write_all("h💖".as_bytes())?;
+    /// assert_eq!(writer.into_inner(), b"\0h\xD8\x3D\xDC\x96");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    struct Utf16Writer([u16; 2]) => encode_utf16;
+}
+
+writer_common_impl! {
+    /// A writer that encodes text to a byte stream of UTF-32 encoded text.
+    ///
+    /// Text can be written with [`fmt::Write`], such as with the [`write!`] macro, or as UTF-8
+    /// bytes with [`io::Write`][Write]. Since both traits provide a `write_fmt` method, only one
+    /// of them should be imported where [`write!`] is used. Each write is passed directly to the
+    /// underlying writer, so wrapping it in a [`BufWriter`][std::io::BufWriter] is recommended
+    /// when writing many small pieces of text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fmt::Write;
+    /// use widestring::{encoding::ByteOrder, io::Utf32Writer};
+    ///
+    /// let mut writer = Utf32Writer::new(Vec::new(), ByteOrder::Little);
+    /// write!(writer, "{}", 'h').unwrap();
+    /// assert_eq!(writer.into_inner(), b"h\0\0\0");
+    /// ```
+    struct Utf32Writer([u32; 4]) => encode_utf32;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{encoding::ByteOrder, io::*, *};
+    use std::io::{self, Read, Write};
+
+    /// A reader that returns at most one byte per read.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((&b, rest)), Some(out)) => {
+                    *out = b;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn utf16_reader() {
+        let bytes = b"\0a\xD8\x3D\xDC\x96\0\n\0b";
+        let mut reader = Utf16Reader::with_capacity(0, Trickle(bytes), ByteOrder::Big);
+        let mut s = Utf16String::new();
+        assert_eq!(reader.read_line(&mut s).unwrap(), 4);
+        assert_eq!(s, utf16str!("a💖\n"));
+        assert_eq!(reader.read_char().unwrap(), Some('b'));
+        assert_eq!(reader.read_char().unwrap(), None);
+
+        let mut reader = Utf16Reader::with_capacity(3, Trickle(bytes), ByteOrder::Big);
+        let mut s = Utf16String::new();
+        while reader.read_chunk(&mut s).unwrap() != 0 {}
+        assert_eq!(s, utf16str!("a💖\nb"));
+
+        let bytes = b"\x3D\xD8a\0\x96\xDC";
+        let reader = Utf16Reader::new(&bytes[..], ByteOrder::Little);
+        let chars: Vec<_> = reader.map(|r| r.map_err(|e| e.kind())).collect();
+        assert_eq!(
+            chars,
+            [
+                Err(io::ErrorKind::InvalidData),
+                Ok('a'),
+                Err(io::ErrorKind::InvalidData)
+            ]
+        );
+
+        let mut reader = Utf16Reader::new(&b"a\0b"[..], ByteOrder::Little);
+        assert_eq!(reader.read_char().unwrap(), Some('a'));
+        assert_eq!(
+            reader.read_char().unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+        assert_eq!(reader.read_char().unwrap(), None);
+
+        let reader = Utf16Reader::new(&b"a\0b"[..], ByteOrder::Little);
+        let chars: Vec<_> = reader.map(|r| r.map_err(|e| e.kind())).collect();
+        assert_eq!(chars, [Ok('a'), Err(io::ErrorKind::UnexpectedEof)]);
+    }
+
+    #[test]
+    fn utf32_reader() {
+        let bytes = b"\x96\xF4\x01\0\n\0\0\0\0\xD8\0\0";
+        let mut reader = Utf32Reader::with_capacity(5, Trickle(bytes), ByteOrder::Little);
+        let mut s = Utf32String::new();
+        assert_eq!(reader.read_line(&mut s).unwrap(), 2);
+        assert_eq!(s, utf32str!("💖\n"));
+        assert_eq!(
+            reader.read_to_string(&mut s).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(reader.read_char().unwrap(), None);
+    }
+
+    #[test]
+    fn utf16_writer() {
+        let mut writer = Utf16Writer::new(Vec::new(), ByteOrder::Little).newline(Newline::CrLf);
+        writer.write_bom().unwrap();
+        fmt::Write::write_str(&mut writer, "a\r").unwrap();
+        fmt::Write::write_str(&mut writer, "\nb\n").unwrap();
+        assert_eq!(writer.into_inner(), b"\xFF\xFEa\0\r\0\n\0b\0\r\0\n\0");
+
+        // UTF-8 sequences split across writes
+        let mut writer = Utf16Writer::new(Vec::new(), ByteOrder::Big);
+        for b in "a💖".as_bytes() {
+            assert_eq!(writer.write(&[*b]).unwrap(), 1);
+        }
+        assert_eq!(writer.write(b"\xF0\x9F\x92\x96b").unwrap(), 5);
+        assert_eq!(writer.get_ref(), b"\0a\xD8\x3D\xDC\x96\xD8\x3D\xDC\x96\0b");
+
+        let mut writer = Utf16Writer::new(Vec::new(), ByteOrder::Big);
+        assert_eq!(writer.write(b"a\xFFb").unwrap(), 1);
+        assert_eq!(
+            writer.write(b"\xFFb").unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(writer.write(b"\xF0").unwrap(), 1);
+        assert_eq!(
+            writer.write(b"b").unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(writer.into_inner(), b"\0a");
+    }
+
+    #[test]
+    fn utf32_writer() {
+        let mut writer = Utf32Writer::new(Vec::new(), ByteOrder::Big);
+        writer.write_bom().unwrap();
+        writer.write_all("💖\n".as_bytes()).unwrap();
+        assert_eq!(writer.into_inner(), b"\0\0\xFE\xFF\0\x01\xF4\x96\0\0\0\n");
+    }
+}
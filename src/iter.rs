@@ -1,6 +1,7 @@
 //! Iterators for encoding and decoding slices of string data.
 
 use crate::{
+    casefold::{fold_full, fold_simple},
     decode_utf16_surrogate_pair,
     error::{DecodeUtf16Error, DecodeUtf32Error},
     is_utf16_high_surrogate, is_utf16_low_surrogate, is_utf16_surrogate,
@@ -440,3 +441,216 @@ where
         self.iter.next_back().map(|c| c as u32)
     }
 }
+
+/// An iterator that maps an iterator of [`char`][prim@char]s to their full Unicode case folding.
+///
+/// Full case folding may map a single [`char`][prim@char] to multiple [`char`][prim@char]s, such
+/// as `ß` to `ss`.
+///
+/// This struct is created by the `casefold` method on UTF strings. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct CaseFold<I>
+where
+    I: Iterator<Item = char>,
+{
+    iter: I,
+    folded: core::slice::Iter<'static, char>,
+}
+
+impl<I> CaseFold<I>
+where
+    I: Iterator<Item = char>,
+{
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            folded: [].iter(),
+        }
+    }
+}
+
+impl<I> Iterator for CaseFold<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(&c) = self.folded.next() {
+            return Some(c);
+        }
+        let c = self.iter.next()?;
+        match fold_full(c) {
+            Some(folded) => {
+                self.folded = folded[1..].iter();
+                Some(folded[0])
+            }
+            None => Some(fold_simple(c)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.folded.len();
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(pending),
+            upper
+                .and_then(|upper| upper.checked_mul(3))
+                .and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
+
+impl<I> FusedIterator for CaseFold<I> where I: Iterator<Item = char> + FusedIterator {}
+
+/// An iterator that maps an iterator of [`char`][prim@char]s to their simple Unicode case
+/// folding.
+///
+/// Simple case folding always maps a single [`char`][prim@char] to a single
+/// [`char`][prim@char].
+///
+/// This struct is created by the `casefold_simple` method on UTF strings. See its documentation
+/// for more.
+#[derive(Debug, Clone)]
+pub struct CaseFoldSimple<I>
+where
+    I: Iterator<Item = char>,
+{
+    iter: I,
+}
+
+impl<I> CaseFoldSimple<I>
+where
+    I: Iterator<Item = char>,
+{
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<I> Iterator for CaseFoldSimple<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(fold_simple)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> FusedIterator for CaseFoldSimple<I> where I: Iterator<Item = char> + FusedIterator {}
+
+impl<I> ExactSizeIterator for CaseFoldSimple<I>
+where
+    I: Iterator<Item = char> + ExactSizeIterator,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I> DoubleEndedIterator for CaseFoldSimple<I>
+where
+    I: Iterator<Item = char> + DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(fold_simple)
+    }
+}
+
+macro_rules! normalization_iter {
+    ($(#[$meta:meta])* struct $name:ident($inner:ident);) => {
+        $(#[$meta])*
+        #[cfg(feature = "normalization")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+        #[derive(Clone)]
+        pub struct $name<I>(unicode_normalization::$inner<I>)
+        where
+            I: Iterator<Item = char>;
+
+        #[cfg(feature = "normalization")]
+        impl<I> $name<I>
+        where
+            I: Iterator<Item = char>,
+        {
+            pub(crate) fn new(iter: unicode_normalization::$inner<I>) -> Self {
+                Self(iter)
+            }
+        }
+
+        #[cfg(feature = "normalization")]
+        impl<I> Iterator for $name<I>
+        where
+            I: Iterator<Item = char>,
+        {
+            type Item = char;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next()
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+        }
+
+        #[cfg(feature = "normalization")]
+        impl<I> FusedIterator for $name<I> where I: Iterator<Item = char> + FusedIterator {}
+
+        #[cfg(feature = "normalization")]
+        impl<I> core::fmt::Debug for $name<I>
+        where
+            I: Iterator<Item = char>,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($name)).finish_non_exhaustive()
+            }
+        }
+    };
+}
+
+normalization_iter! {
+    /// An iterator over the Unicode Normalization Form C (canonical composition) of an iterator
+    /// of [`char`][prim@char]s.
+    ///
+    /// This struct is created by the `nfc` method on UTF strings. See its documentation for more.
+    struct Nfc(Recompositions);
+}
+
+normalization_iter! {
+    /// An iterator over the Unicode Normalization Form D (canonical decomposition) of an iterator
+    /// of [`char`][prim@char]s.
+    ///
+    /// This struct is created by the `nfd` method on UTF strings. See its documentation for more.
+    struct Nfd(Decompositions);
+}
+
+normalization_iter! {
+    /// An iterator over the Unicode Normalization Form KC (compatibility composition) of an
+    /// iterator of [`char`][prim@char]s.
+    ///
+    /// This struct is created by the `nfkc` method on UTF strings. See its documentation for more.
+    struct Nfkc(Recompositions);
+}
+
+normalization_iter! {
+    /// An iterator over the Unicode Normalization Form KD (compatibility decomposition) of an
+    /// iterator of [`char`][prim@char]s.
+    ///
+    /// This struct is created by the `nfkd` method on UTF strings. See its documentation for more.
+    struct Nfkd(Decompositions);
+}
@@ -67,6 +67,17 @@
 //! Other types such as the string slices do not require allocation and can be used in a `no_std`
 //! environment, even without the [`alloc`](https://doc.rust-lang.org/stable/alloc/index.html)
 //! crate.
+//! The `std` cargo feature additionally enables the `io` module, which decodes and encodes byte
+//! streams of UTF-16 and UTF-32 text with any [`Read`][std::io::Read] or
+//! [`Write`][std::io::Write] implementation.
+//!
+//! The optional `normalization` cargo feature enables Unicode normalization of [`Utf16Str`] and
+//! [`Utf32Str`], such as `Utf16Str::nfc`. The optional `segmentation` cargo feature enables
+//! grapheme cluster and word segmentation of them, such as `Utf16Str::graphemes`.
+//!
+//! The optional `serde` cargo feature implements `Serialize` and `Deserialize` for all string
+//! types. See the `serde_lossy` module for serializing strings with undefined encoding as strings
+//! instead of sequences of code units.
 //!
 //! # Remarks on UTF-16 and UTF-32
 //!
@@ -238,12 +249,23 @@ use crate::error::{DecodeUtf16Error, DecodeUtf32Error};
 use alloc::vec::Vec;
 use core::fmt::Write;
 
+mod casefold;
+pub mod encoding;
 pub mod error;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod io;
 pub mod iter;
 mod macros;
+pub mod pattern;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 mod platform;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde_lossy;
 pub mod ucstr;
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
@@ -256,6 +278,7 @@ pub mod utfstr;
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub mod utfstring;
+pub mod wtf8;
 
 #[doc(hidden)]
 pub use macros::internals;
@@ -271,6 +294,10 @@ pub use utfstr::{Utf16Str, Utf32Str, WideUtfStr};
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub use utfstring::{Utf16String, Utf32String, WideUtfString};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use wtf8::Wtf8Buf;
+pub use wtf8::Wtf8Str;
 
 #[cfg(not(windows))]
 /// Alias for [`u16`] or [`u32`] depending on platform. Intended to match typical C `wchar_t` size
@@ -493,6 +520,32 @@ pub fn encode_utf32<I: IntoIterator<Item = char>>(iter: I) -> iter::EncodeUtf32<
     iter::EncodeUtf32::new(iter.into_iter())
 }
 
+/// Returns the length in code units of a string slice encoded as UTF-16.
+///
+/// This computes the exact length without allocating, so it can be used to size a buffer for
+/// [`Utf16Str::from_str_into`] or to reserve capacity. It is a `const fn`, so it can also be used
+/// to size arrays at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use widestring::utf16_len;
+///
+/// const LEN: usize = utf16_len("a\u{e9}💖");
+/// assert_eq!(LEN, 4);
+/// assert_eq!(utf16_len(""), 0);
+/// ```
+#[must_use]
+pub const fn utf16_len(s: &str) -> usize {
+    let mut bytes = s.as_bytes();
+    let mut len = 0;
+    while let Some((ch, rest)) = internals::next_code_point(bytes) {
+        bytes = rest;
+        len += if (ch & 0xFFFF) == ch { 1 } else { 2 };
+    }
+    len
+}
+
 /// Debug implementation for any U16 string slice.
 ///
 /// Properly encoded input data will output valid strings with escape sequences, however invalid
@@ -592,6 +645,150 @@ const fn is_utf16_low_surrogate(u: u16) -> bool {
     u >= 0xDC00 && u <= 0xDFFF
 }
 
+/// Decodes the UTF-8 sequence at the start of `bytes`, returning the [`char`] and its length.
+///
+/// If the sequence is invalid, returns the length of the invalid sequence the same way as
+/// [`Utf8Error::error_len`][error::Utf8Error::error_len], or [`None`] if `bytes` ends with an
+/// incomplete sequence.
+#[cfg(feature = "alloc")]
+fn decode_utf8_char(bytes: &[u8]) -> Result<(char, usize), Option<usize>> {
+    let b0 = bytes[0];
+    let (len, second) = match b0 {
+        0x00..=0x7F => return Ok((b0 as char, 1)),
+        0xC2..=0xDF => (2, 0x80..=0xBF),
+        0xE0 => (3, 0xA0..=0xBF),
+        0xED => (3, 0x80..=0x9F),
+        0xE1..=0xEF => (3, 0x80..=0xBF),
+        0xF0 => (4, 0x90..=0xBF),
+        0xF1..=0xF3 => (4, 0x80..=0xBF),
+        0xF4 => (4, 0x80..=0x8F),
+        _ => return Err(Some(1)),
+    };
+    let mut code = (b0 & (0x7F >> len)) as u32;
+    for i in 1..len {
+        let b = *bytes.get(i).ok_or(None)?;
+        let valid = if i == 1 {
+            second.contains(&b)
+        } else {
+            (0x80..=0xBF).contains(&b)
+        };
+        if !valid {
+            return Err(Some(i));
+        }
+        code = code << 6 | (b & 0x3F) as u32;
+    }
+    // SAFETY: Checked to be a valid UTF-8 sequence
+    Ok((unsafe { char::from_u32_unchecked(code) }, len))
+}
+
+/// Encodes `chars` into `buf`, using `encode` to encode each [`char`] into at most `N` elements
+/// and return their count.
+///
+/// Returns the number of elements written, or the number of elements required if `buf` is too
+/// small.
+fn encode_chars_into<T: Copy + Default, const N: usize>(
+    mut chars: impl Iterator<Item = char>,
+    buf: &mut [T],
+    encode: impl Fn(char, &mut [T; N]) -> usize,
+) -> Result<usize, error::BufferTooSmall> {
+    let mut written = 0;
+    let mut units = [T::default(); N];
+    while let Some(c) = chars.next() {
+        let len = encode(c, &mut units);
+        match buf.get_mut(written..written + len) {
+            Some(dst) => dst.copy_from_slice(&units[..len]),
+            None => {
+                let rest: usize = chars.map(|c| encode(c, &mut units)).sum();
+                return Err(error::BufferTooSmall::new(written + len + rest));
+            }
+        }
+        written += len;
+    }
+    Ok(written)
+}
+
+/// Encodes a [`char`] as UTF-8 for [`encode_chars_into`].
+#[inline]
+fn encode_utf8_unit(c: char, buf: &mut [u8; 4]) -> usize {
+    c.encode_utf8(buf).len()
+}
+
+/// Encodes a [`char`] as UTF-16 for [`encode_chars_into`].
+#[inline]
+fn encode_utf16_unit(c: char, buf: &mut [u16; 2]) -> usize {
+    c.encode_utf16(buf).len()
+}
+
+/// Encodes a [`char`] as UTF-32 for [`encode_chars_into`].
+#[inline]
+fn encode_utf32_unit(c: char, buf: &mut [u32; 1]) -> usize {
+    buf[0] = c as u32;
+    1
+}
+
+/// Maps an ASCII code unit to its lower case equivalent, leaving all other values unchanged.
+#[inline(always)]
+fn ascii_lowercase<T: Copy + From<u8>>(u: T) -> T
+where
+    u8: TryFrom<T>,
+{
+    u8::try_from(u).map_or(u, |b| T::from(b.to_ascii_lowercase()))
+}
+
+/// Maps an ASCII code unit to its upper case equivalent, leaving all other values unchanged.
+#[inline(always)]
+fn ascii_uppercase<T: Copy + From<u8>>(u: T) -> T
+where
+    u8: TryFrom<T>,
+{
+    u8::try_from(u).map_or(u, |b| T::from(b.to_ascii_uppercase()))
+}
+
+/// Converts bytes to a vector of code units, using `f` to convert each chunk of `N` bytes.
+#[cfg(feature = "alloc")]
+fn units_from_bytes<T, const N: usize>(
+    bytes: &[u8],
+    f: fn([u8; N]) -> T,
+) -> Result<Vec<T>, crate::error::InvalidByteLength> {
+    if bytes.len() % N != 0 {
+        return Err(crate::error::InvalidByteLength::new(bytes.len(), N));
+    }
+    Ok(bytes
+        .chunks_exact(N)
+        .map(|chunk| f(chunk.try_into().unwrap()))
+        .collect())
+}
+
+/// Converts code units to a vector of bytes, using `f` to convert each code unit to `N` bytes.
+#[cfg(feature = "alloc")]
+fn units_to_bytes<T: Copy, const N: usize>(units: &[T], f: fn(T) -> [u8; N]) -> Vec<u8> {
+    let mut v = Vec::with_capacity(units.len() * N);
+    for &unit in units {
+        v.extend_from_slice(&f(unit));
+    }
+    v
+}
+
+/// Compares two slices of UTF-16 code units in code point order.
+///
+/// Code units in the range `0xE000..=0xFFFF` are moved below the surrogate range, so that
+/// supplementary characters sort after all other characters just like they do in UTF-8 and UTF-32.
+/// Surrogates, including unpaired ones, are moved above that range, so unpaired surrogates sort
+/// after all characters up to `U+FFFF`.
+fn cmp_utf16_code_point(a: &[u16], b: &[u16]) -> core::cmp::Ordering {
+    #[inline(always)]
+    fn fixup(u: u16) -> u16 {
+        if u >= 0xE000 {
+            u - 0x800
+        } else if u >= 0xD800 {
+            u + 0x2000
+        } else {
+            u
+        }
+    }
+    a.iter().map(|&u| fixup(u)).cmp(b.iter().map(|&u| fixup(u)))
+}
+
 /// Convert a UTF-16 surrogate pair to a `char`. Does not validate if the surrogates are valid.
 #[inline(always)]
 unsafe fn decode_utf16_surrogate_pair(high: u16, low: u16) -> char {
@@ -184,6 +184,7 @@ implement_utf32_macro! {
 #[macro_export]
 macro_rules! widestr {
     ($text:expr) => {{
+        #[allow(unused_imports)]
         use $crate::*;
         u32str!($text)
     }};
@@ -195,6 +196,7 @@ macro_rules! widestr {
 #[macro_export]
 macro_rules! wideutfstr {
     ($text:expr) => {{
+        #[allow(unused_imports)]
         use $crate::*;
         utf32str!($text)
     }};
@@ -206,6 +208,7 @@ macro_rules! wideutfstr {
 #[macro_export]
 macro_rules! widecstr {
     ($text:expr) => {{
+        #[allow(unused_imports)]
         use $crate::*;
         u32cstr!($text)
     }};
@@ -217,6 +220,7 @@ macro_rules! widecstr {
 #[macro_export]
 macro_rules! widestr {
     ($text:expr) => {{
+        #[allow(unused_imports)]
         use $crate::*;
         u16str!($text)
     }};
@@ -228,6 +232,7 @@ macro_rules! widestr {
 #[macro_export]
 macro_rules! wideutfstr {
     ($text:expr) => {{
+        #[allow(unused_imports)]
         use $crate::*;
         utf16str!($text)
     }};
@@ -239,6 +244,7 @@ macro_rules! wideutfstr {
 #[macro_export]
 macro_rules! widecstr {
     ($text:expr) => {{
+        #[allow(unused_imports)]
         use $crate::*;
         u16cstr!($text)
     }};
@@ -277,13 +283,7 @@ pub mod internals {
 
     // A const implementation of `s.chars().map(|ch| ch.len_utf16()).sum()`
     pub const fn length_as_utf16(s: &str) -> usize {
-        let mut bytes = s.as_bytes();
-        let mut len = 0;
-        while let Some((ch, rest)) = next_code_point(bytes) {
-            bytes = rest;
-            len += if (ch & 0xFFFF) == ch { 1 } else { 2 };
-        }
-        len
+        crate::utf16_len(s)
     }
 
     // A const implementation of `s.chars().len()`
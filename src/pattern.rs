@@ -0,0 +1,351 @@
+//! Patterns for searching UTF string slices.
+//!
+//! This module contains the [`Pattern`] trait, which is the string pattern API used by the
+//! searching methods of [`Utf16Str`] and [`Utf32Str`] such as [`find`][Utf16Str::find],
+//! [`contains`][Utf16Str::contains], and [`starts_with`][Utf16Str::starts_with]. It is similar
+//! to the pattern API of [`str`], and is implemented for the same kinds of types:
+//!
+//! - [`char`]
+//! - [`&str`][str], [`&&str`][str] and [`&String`][alloc::string::String]
+//! - [`&Utf16Str`][Utf16Str] and [`&Utf16String`][crate::Utf16String] when searching
+//!   [`Utf16Str`], or [`&Utf32Str`][Utf32Str] and [`&Utf32String`][crate::Utf32String] when
+//!   searching [`Utf32Str`]
+//! - [`[char; N]`][array], [`&[char; N]`][array] and [`&[char]`][slice], which match any of the
+//!   [`char`]s they contain
+//! - [`FnMut(char) -> bool`][FnMut] closures, which match any [`char`] for which they return
+//!   `true`
+//!
+//! All positions reported by a pattern are offsets of code units into the searched string slice,
+//! and always lie on [`char`] boundaries, so they can be used directly to index or split the
+//! string slice.
+//!
+//! # Examples
+//!
+//! ```
+//! use widestring::utf16str;
+//!
+//! let s = utf16str!("Löwe 老虎 Léopard");
+//!
+//! assert_eq!(s.find('L'), Some(0));
+//! assert_eq!(s.find("虎"), Some(6));
+//! assert_eq!(s.find(utf16str!("pard")), Some(11));
+//! assert_eq!(s.find(&['é', 'ö'][..]), Some(1));
+//! assert_eq!(s.find(char::is_whitespace), Some(4));
+//! assert_eq!(s.find('x'), None);
+//! ```
+
+use crate::{Utf16Str, Utf32Str};
+#[cfg(feature = "alloc")]
+use crate::{Utf16String, Utf32String};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// A string pattern that can be searched for in a UTF string slice of type `S`.
+///
+/// See the [module documentation][crate::pattern] for the types that implement this trait.
+///
+/// All offsets and lengths returned by the methods of this trait are measured in code units of
+/// the string slice `S`, and always lie on [`char`] boundaries.
+pub trait Pattern<S: ?Sized>: Sized {
+    /// Returns the code unit range `(start, end)` of the first match of the pattern in
+    /// `haystack`, or [`None`] if the pattern does not match.
+    fn find_in(&mut self, haystack: &S) -> Option<(usize, usize)>;
+
+    /// Returns the code unit range `(start, end)` of the last match of the pattern in `haystack`,
+    /// or [`None`] if the pattern does not match.
+    fn rfind_in(&mut self, haystack: &S) -> Option<(usize, usize)>;
+
+    /// Returns the length of the match if the pattern matches at the start of `haystack`.
+    fn match_prefix(&mut self, haystack: &S) -> Option<usize>;
+
+    /// Returns the length of the match if the pattern matches at the end of `haystack`.
+    fn match_suffix(&mut self, haystack: &S) -> Option<usize>;
+
+    /// Returns whether the pattern matches anywhere in `haystack`.
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_contained_in(mut self, haystack: &S) -> bool {
+        self.find_in(haystack).is_some()
+    }
+
+    /// Returns whether the pattern matches at the start of `haystack`.
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_prefix_of(mut self, haystack: &S) -> bool {
+        self.match_prefix(haystack).is_some()
+    }
+
+    /// Returns whether the pattern matches at the end of `haystack`.
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_suffix_of(mut self, haystack: &S) -> bool {
+        self.match_suffix(haystack).is_some()
+    }
+}
+
+/// Maximum length of a needle that is buffered on the stack by [`find_seq`] and [`rfind_seq`].
+const NEEDLE_BUF_LEN: usize = 32;
+
+/// Finds the first position of a sequence of code units in `haystack`.
+///
+/// `needle` must yield exactly `needle_len` code units. An empty needle matches at the start of
+/// the haystack.
+///
+/// Needles of up to [`NEEDLE_BUF_LEN`] code units are collected into a buffer once, so that
+/// needles such as `str` are not transcoded again at every position. Longer needles are only
+/// iterated again at positions where their first code unit matches.
+pub(crate) fn find_seq<T, N>(haystack: &[T], needle: N, needle_len: usize) -> Option<usize>
+where
+    T: Copy + PartialEq,
+    N: Iterator<Item = T> + Clone,
+{
+    let last = haystack.len().checked_sub(needle_len)?;
+    let first = match needle.clone().next() {
+        Some(first) => first,
+        None => return Some(0),
+    };
+    if needle_len <= NEEDLE_BUF_LEN {
+        let mut buf = [first; NEEDLE_BUF_LEN];
+        buf.iter_mut().zip(needle).for_each(|(b, u)| *b = u);
+        let needle = &buf[..needle_len];
+        return haystack.windows(needle_len).position(|w| w == needle);
+    }
+    (0..=last).find(|&i| {
+        haystack[i] == first
+            && needle
+                .clone()
+                .eq(haystack[i..i + needle_len].iter().copied())
+    })
+}
+
+/// Finds the last position of a sequence of code units in `haystack`.
+///
+/// `needle` must yield exactly `needle_len` code units. An empty needle matches at the end of the
+/// haystack. See [`find_seq`] for how the needle is compared.
+pub(crate) fn rfind_seq<T, N>(haystack: &[T], needle: N, needle_len: usize) -> Option<usize>
+where
+    T: Copy + PartialEq,
+    N: Iterator<Item = T> + Clone,
+{
+    let last = haystack.len().checked_sub(needle_len)?;
+    let first = match needle.clone().next() {
+        Some(first) => first,
+        None => return Some(last),
+    };
+    if needle_len <= NEEDLE_BUF_LEN {
+        let mut buf = [first; NEEDLE_BUF_LEN];
+        buf.iter_mut().zip(needle).for_each(|(b, u)| *b = u);
+        let needle = &buf[..needle_len];
+        return haystack.windows(needle_len).rposition(|w| w == needle);
+    }
+    (0..=last).rev().find(|&i| {
+        haystack[i] == first
+            && needle
+                .clone()
+                .eq(haystack[i..i + needle_len].iter().copied())
+    })
+}
+
+/// Returns whether `haystack` starts with the sequence of code units in `needle`.
+#[inline]
+fn starts_with_seq<T, N>(haystack: &[T], needle: N, needle_len: usize) -> bool
+where
+    T: Copy + PartialEq,
+    N: Iterator<Item = T>,
+{
+    needle_len <= haystack.len() && needle.eq(haystack[..needle_len].iter().copied())
+}
+
+/// Returns whether `haystack` ends with the sequence of code units in `needle`.
+#[inline]
+fn ends_with_seq<T, N>(haystack: &[T], needle: N, needle_len: usize) -> bool
+where
+    T: Copy + PartialEq,
+    N: Iterator<Item = T>,
+{
+    needle_len <= haystack.len()
+        && needle.eq(haystack[haystack.len() - needle_len..].iter().copied())
+}
+
+/// Implements [`Pattern`] for a type that matches a sequence of code units.
+///
+/// `$needle` must evaluate to a tuple of a cloneable iterator over the code units of the pattern
+/// and the number of code units it yields.
+macro_rules! seq_pattern_impl {
+    ($(#[$m:meta])* [$($g:tt)*] $ty:ty => $utfstr:ident, |$this:ident| $needle:expr) => {
+        $(#[$m])*
+        impl<$($g)*> Pattern<$utfstr> for $ty {
+            #[inline]
+            fn find_in(&mut self, haystack: &$utfstr) -> Option<(usize, usize)> {
+                let $this = &*self;
+                let (needle, len) = $needle;
+                find_seq(haystack.as_slice(), needle, len).map(|i| (i, i + len))
+            }
+
+            #[inline]
+            fn rfind_in(&mut self, haystack: &$utfstr) -> Option<(usize, usize)> {
+                let $this = &*self;
+                let (needle, len) = $needle;
+                rfind_seq(haystack.as_slice(), needle, len).map(|i| (i, i + len))
+            }
+
+            #[inline]
+            fn match_prefix(&mut self, haystack: &$utfstr) -> Option<usize> {
+                let $this = &*self;
+                let (needle, len) = $needle;
+                if starts_with_seq(haystack.as_slice(), needle, len) {
+                    Some(len)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn match_suffix(&mut self, haystack: &$utfstr) -> Option<usize> {
+                let $this = &*self;
+                let (needle, len) = $needle;
+                if ends_with_seq(haystack.as_slice(), needle, len) {
+                    Some(len)
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+/// Implements [`Pattern`] for a type that matches single [`char`]s by a predicate.
+macro_rules! char_pattern_impl {
+    ($(#[$m:meta])* [$($g:tt)*] $ty:ty => $utfstr:ident, |$this:ident, $c:ident| $matches:expr) => {
+        $(#[$m])*
+        impl<$($g)*> Pattern<$utfstr> for $ty {
+            #[inline]
+            fn find_in(&mut self, haystack: &$utfstr) -> Option<(usize, usize)> {
+                let $this = self;
+                let mut iter = haystack.char_indices();
+                while let Some((i, $c)) = iter.next() {
+                    if $matches {
+                        return Some((i, iter.offset()));
+                    }
+                }
+                None
+            }
+
+            #[inline]
+            fn rfind_in(&mut self, haystack: &$utfstr) -> Option<(usize, usize)> {
+                let $this = self;
+                let mut end = haystack.len();
+                let mut iter = haystack.char_indices();
+                while let Some((i, $c)) = iter.next_back() {
+                    if $matches {
+                        return Some((i, end));
+                    }
+                    end = i;
+                }
+                None
+            }
+
+            #[inline]
+            fn match_prefix(&mut self, haystack: &$utfstr) -> Option<usize> {
+                let $this = self;
+                let mut iter = haystack.char_indices();
+                match iter.next() {
+                    Some((_, $c)) if $matches => Some(iter.offset()),
+                    _ => None,
+                }
+            }
+
+            #[inline]
+            fn match_suffix(&mut self, haystack: &$utfstr) -> Option<usize> {
+                let $this = self;
+                match haystack.char_indices().next_back() {
+                    Some((i, $c)) if $matches => Some(haystack.len() - i),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+macro_rules! pattern_impls {
+    (
+        $utfstr:ident, $utfstring:ident,
+        |$s:ident| $encode_str:expr,
+        |$ch:ident, $buf:ident| $encode_char:expr
+    ) => {
+        seq_pattern_impl! {
+            [] char => $utfstr, |this| {
+                let $ch = *this;
+                let mut $buf = [0; 2];
+                let units = $encode_char;
+                let len = units.len();
+                ($buf.into_iter().take(len), len)
+            }
+        }
+
+        seq_pattern_impl! {
+            ['a] &'a $utfstr => $utfstr, |this| {
+                (this.as_slice().iter().copied(), this.len())
+            }
+        }
+
+        seq_pattern_impl! {
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            ['a] &'a $utfstring => $utfstr, |this| {
+                (this.as_slice().iter().copied(), this.len())
+            }
+        }
+
+        seq_pattern_impl! {
+            ['a] &'a str => $utfstr, |this| {
+                let $s: &str = this;
+                $encode_str
+            }
+        }
+
+        seq_pattern_impl! {
+            ['a, 'b] &'b &'a str => $utfstr, |this| {
+                let $s: &str = this;
+                $encode_str
+            }
+        }
+
+        seq_pattern_impl! {
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            ['a] &'a String => $utfstr, |this| {
+                let $s: &str = this.as_str();
+                $encode_str
+            }
+        }
+
+        char_pattern_impl! {
+            ['a] &'a [char] => $utfstr, |this, c| this.contains(&c)
+        }
+
+        char_pattern_impl! {
+            [const N: usize] [char; N] => $utfstr, |this, c| this.contains(&c)
+        }
+
+        char_pattern_impl! {
+            ['a, const N: usize] &'a [char; N] => $utfstr, |this, c| this.contains(&c)
+        }
+
+        char_pattern_impl! {
+            [F: FnMut(char) -> bool] F => $utfstr, |this, c| this(c)
+        }
+    };
+}
+
+pattern_impls! {
+    Utf16Str, Utf16String,
+    |s| (s.encode_utf16(), crate::utf16_len(s)),
+    |c, buf| c.encode_utf16(&mut buf)
+}
+
+pattern_impls! {
+    Utf32Str, Utf32String,
+    |s| (s.chars().map(u32::from), s.chars().count()),
+    |c, buf| { buf[0] = u32::from(c); &buf[..1] }
+}
@@ -1,4 +1,6 @@
 use std::ffi::{OsStr, OsString};
+#[cfg(unix)]
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
 
 pub(crate) fn os_to_wide(s: &OsStr) -> Vec<u16> {
     s.to_string_lossy().encode_utf16().collect()
@@ -7,3 +9,21 @@ pub(crate) fn os_to_wide(s: &OsStr) -> Vec<u16> {
 pub(crate) fn os_from_wide(s: &[u16]) -> OsString {
     OsString::from(String::from_utf16_lossy(s))
 }
+
+#[cfg(unix)]
+pub(crate) fn os_to_wide_wtf8(s: &OsStr) -> Vec<u16> {
+    let mut v = Vec::new();
+    crate::wtf8::decode_wtf8(s.as_bytes(), &mut v);
+    v
+}
+
+#[cfg(unix)]
+pub(crate) fn os_from_wide_wtf8(s: &[u16]) -> OsString {
+    let mut v = Vec::new();
+    crate::wtf8::encode_wtf8(s.iter().copied(), &mut v);
+    OsString::from_vec(v)
+}
+
+// Other platforms can only store valid Unicode in an `OsString`
+#[cfg(not(unix))]
+pub(crate) use self::{os_from_wide as os_from_wide_wtf8, os_to_wide as os_to_wide_wtf8};
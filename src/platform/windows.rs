@@ -9,3 +9,6 @@ pub(crate) fn os_to_wide(s: &OsStr) -> Vec<u16> {
 pub(crate) fn os_from_wide(s: &[u16]) -> OsString {
     OsString::from_wide(s)
 }
+
+// Windows stores ill-formed UTF-16 natively, so no WTF-8 conversion is needed
+pub(crate) use self::{os_from_wide as os_from_wide_wtf8, os_to_wide as os_to_wide_wtf8};
@@ -0,0 +1,219 @@
+//! [`serde`] implementations for the wide string types.
+//!
+//! UTF string types are always valid Unicode, so they serialize as strings. Wide strings with
+//! undefined encoding serialize losslessly as sequences of code units, and can be serialized as
+//! lossy strings instead with [`serde_lossy`][crate::serde_lossy].
+
+use crate::{U16CStr, U16Str, U32CStr, U32Str, Utf16Str, Utf32Str};
+#[cfg(feature = "alloc")]
+use crate::{U16CString, U16String, U32CString, U32String, Utf16String, Utf32String};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::{fmt, marker::PhantomData};
+#[cfg(feature = "alloc")]
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+macro_rules! serialize_as_str {
+    ($($utfstr:ty),+) => {$(
+        impl Serialize for $utfstr {
+            #[inline]
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+    )+};
+}
+
+serialize_as_str!(Utf16Str, Utf32Str);
+#[cfg(feature = "alloc")]
+serialize_as_str!(Utf16String, Utf32String);
+
+macro_rules! serialize_as_units {
+    ($($ustr:ty),+) => {$(
+        impl Serialize for $ustr {
+            #[inline]
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq(self.as_slice())
+            }
+        }
+    )+};
+}
+
+serialize_as_units!(U16Str, U32Str, U16CStr, U32CStr);
+#[cfg(feature = "alloc")]
+serialize_as_units!(U16String, U32String, U16CString, U32CString);
+
+/// Collects a sequence of code units into a vector.
+#[cfg(feature = "alloc")]
+fn visit_units<'de, A, C>(mut seq: A) -> Result<Vec<C>, A::Error>
+where
+    A: SeqAccess<'de>,
+    C: Deserialize<'de>,
+{
+    // Don't trust the size hint too much, as it may come from untrusted input
+    let mut v = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+    while let Some(unit) = seq.next_element()? {
+        v.push(unit);
+    }
+    Ok(v)
+}
+
+/// A [`Visitor`] for the owned wide string types.
+#[cfg(feature = "alloc")]
+pub(crate) struct StringVisitor<T>(pub(crate) PhantomData<T>);
+
+#[cfg(feature = "alloc")]
+macro_rules! deserialize_utf {
+    ($($utfstring:ident),+) => {$(
+        impl<'de> Visitor<'de> for StringVisitor<$utfstring> {
+            type Value = $utfstring;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok($utfstring::from_str(v))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $utfstring {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(StringVisitor::<Self>(PhantomData))
+            }
+        }
+    )+};
+}
+
+#[cfg(feature = "alloc")]
+deserialize_utf!(Utf16String, Utf32String);
+
+#[cfg(feature = "alloc")]
+macro_rules! deserialize_ustring {
+    ($($ustring:ident),+) => {$(
+        impl<'de> Visitor<'de> for StringVisitor<$ustring> {
+            type Value = $ustring;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string or a sequence of code units")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok($ustring::from_str(v))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+                visit_units(seq).map($ustring::from_vec)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ustring {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                // Only self-describing formats can tell strings and sequences apart
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_any(StringVisitor::<Self>(PhantomData))
+                } else {
+                    deserializer.deserialize_seq(StringVisitor::<Self>(PhantomData))
+                }
+            }
+        }
+    )+};
+}
+
+#[cfg(feature = "alloc")]
+deserialize_ustring!(U16String, U32String);
+
+#[cfg(feature = "alloc")]
+macro_rules! deserialize_ucstring {
+    ($($ucstring:ident),+) => {$(
+        impl<'de> Visitor<'de> for StringVisitor<$ucstring> {
+            type Value = $ucstring;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string or a sequence of code units without nul values")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                $ucstring::from_str(v).map_err(E::custom)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+                $ucstring::from_vec(visit_units(seq)?).map_err(de::Error::custom)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ucstring {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                // Only self-describing formats can tell strings and sequences apart
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_any(StringVisitor::<Self>(PhantomData))
+                } else {
+                    deserializer.deserialize_seq(StringVisitor::<Self>(PhantomData))
+                }
+            }
+        }
+    )+};
+}
+
+#[cfg(feature = "alloc")]
+deserialize_ucstring!(U16CString, U32CString);
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use serde_test::{assert_ser_tokens, Token};
+
+    #[test]
+    fn serialize() {
+        assert_ser_tokens(utf16str!("a💖"), &[Token::Str("a💖")]);
+        assert_ser_tokens(utf32str!("a💖"), &[Token::Str("a💖")]);
+        assert_ser_tokens(
+            U16Str::from_slice(&[0x61, 0xD800]),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U16(0x61),
+                Token::U16(0xD800),
+                Token::SeqEnd,
+            ],
+        );
+        assert_ser_tokens(
+            u32cstr!("a"),
+            &[Token::Seq { len: Some(1) }, Token::U32(0x61), Token::SeqEnd],
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn deserialize() {
+        use serde_test::{assert_de_tokens, assert_de_tokens_error, Configure, Readable};
+
+        assert_de_tokens(&Utf16String::from_str("a💖"), &[Token::Str("a💖")]);
+        assert_de_tokens(&Utf32String::from_str("a💖"), &[Token::String("a💖")]);
+
+        let s = U16String::from_vec([0x61, 0xD800]);
+        let tokens = [
+            Token::Seq { len: Some(2) },
+            Token::U16(0x61),
+            Token::U16(0xD800),
+            Token::SeqEnd,
+        ];
+        assert_de_tokens(&s.clone().readable(), &tokens);
+        assert_de_tokens(&s.compact(), &tokens);
+        assert_de_tokens(&U32String::from_str("a💖").readable(), &[Token::Str("a💖")]);
+        assert_de_tokens(
+            &U16CString::from_str("a").unwrap().readable(),
+            &[Token::Str("a")],
+        );
+        assert_de_tokens_error::<Readable<U16CString>>(
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U16(0),
+                Token::U16(0x61),
+                Token::SeqEnd,
+            ],
+            "invalid nul value found at position 0",
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Lossy string serialization of wide strings with undefined encoding.
+//!
+//! By default, wide strings with undefined encoding such as [`U16String`][crate::U16String]
+//! serialize losslessly as a sequence of code units, since they may contain ill-formed data. This
+//! module can be used with serde's `#[serde(with = "...")]` field attribute to serialize them as
+//! strings instead. Ill-formed data, such as unpaired surrogates, is replaced with
+//! [`U+FFFD REPLACEMENT CHARACTER`][char::REPLACEMENT_CHARACTER] (�) when serializing.
+//!
+//! When deserializing, a string is expected, so that lossy strings can also be read back from
+//! formats that are not self-describing.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use serde::{Deserialize, Serialize};
+//! use widestring::U16String;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Key {
+//!     #[serde(with = "widestring::serde_lossy")]
+//!     name: U16String,
+//!     raw: U16String,
+//! }
+//! # }
+//! ```
+
+#[cfg(feature = "alloc")]
+use crate::serde_impls::StringVisitor;
+use crate::{U16CStr, U16Str, U32CStr, U32Str};
+#[cfg(feature = "alloc")]
+use crate::{U16CString, U16String, U32CString, U32String};
+#[cfg(feature = "alloc")]
+use core::marker::PhantomData;
+use serde::{Deserializer, Serializer};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Wide strings that can be serialized as lossy strings by [`serialize`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait SerializeLossy: private::Sealed {
+    #[doc(hidden)]
+    fn serialize_lossy<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+impl<T: ?Sized + SerializeLossy> private::Sealed for &T {}
+
+impl<T: ?Sized + SerializeLossy> SerializeLossy for &T {
+    #[inline]
+    fn serialize_lossy<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize_lossy(serializer)
+    }
+}
+
+macro_rules! serialize_lossy_impl {
+    ($($ustr:ty),+) => {$(
+        impl private::Sealed for $ustr {}
+
+        impl SerializeLossy for $ustr {
+            #[inline]
+            fn serialize_lossy<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(&self.display())
+            }
+        }
+    )+};
+}
+
+serialize_lossy_impl!(U16Str, U32Str, U16CStr, U32CStr);
+#[cfg(feature = "alloc")]
+serialize_lossy_impl!(U16String, U32String, U16CString, U32CString);
+
+/// Owned wide strings that can be deserialized from strings by [`deserialize`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait DeserializeLossy: private::Sealed + Sized {
+    #[doc(hidden)]
+    fn deserialize_lossy<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! deserialize_lossy_impl {
+    ($($ustring:ty),+) => {$(
+        impl DeserializeLossy for $ustring {
+            #[inline]
+            fn deserialize_lossy<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(StringVisitor::<Self>(PhantomData))
+            }
+        }
+    )+};
+}
+
+#[cfg(feature = "alloc")]
+deserialize_lossy_impl!(U16String, U32String, U16CString, U32CString);
+
+/// Serializes a wide string as a lossy string.
+///
+/// See the [module documentation][self] for more.
+#[inline]
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: ?Sized + SerializeLossy,
+    S: Serializer,
+{
+    value.serialize_lossy(serializer)
+}
+
+/// Deserializes a wide string from a string.
+///
+/// See the [module documentation][self] for more.
+#[inline]
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: DeserializeLossy,
+    D: Deserializer<'de>,
+{
+    T::deserialize_lossy(deserializer)
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn lossy() {
+        use crate::*;
+        use serde_test::{assert_tokens, Token};
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Key {
+            #[serde(with = "crate::serde_lossy")]
+            name: U16String,
+        }
+
+        let key = Key {
+            name: U16String::from_str("Software"),
+        };
+        assert_tokens(
+            &key,
+            &[
+                Token::Struct {
+                    name: "Key",
+                    len: 1,
+                },
+                Token::Str("name"),
+                Token::Str("Software"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
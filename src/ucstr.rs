@@ -3,7 +3,7 @@
 //! This module contains wide C string slices and related types.
 
 use crate::{
-    error::{ContainsNul, MissingNulTerminator, NulError},
+    error::{BufferTooSmall, ContainsNul, MissingNulTerminator, NulError},
     U16Str, U32Str,
 };
 #[cfg(feature = "alloc")]
@@ -17,7 +17,7 @@ use core::{
 #[doc(inline)]
 pub use crate::ustr::{
     CharIndicesLossyUtf16, CharIndicesLossyUtf32, CharIndicesUtf16, CharIndicesUtf32,
-    CharsLossyUtf16, CharsLossyUtf32, CharsUtf16, CharsUtf32,
+    CharsLossyUtf16, CharsLossyUtf32, CharsUtf16, CharsUtf32, SplitUnits,
 };
 
 macro_rules! ucstr_common_impl {
@@ -35,6 +35,8 @@ macro_rules! ucstr_common_impl {
         fn display() -> {}
     } => {
         $(#[$ucstr_meta])*
+        // Renamed to `derived_hash_with_manual_eq` after the MSRV
+        #[allow(renamed_and_removed_lints)]
         #[allow(clippy::derive_hash_xor_eq)]
         #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub struct $ucstr {
@@ -803,6 +805,73 @@ macro_rules! ucstr_common_impl {
                 ($ustr::from_slice_mut(split.0), $ustr::from_slice_mut(split.1))
             }
 
+            /// Returns the index of the first occurrence of a sequence of code units in this
+            /// string.
+            ///
+            /// The nul terminator is not included in the search. See
+            /// `find_units` on the wide string slice for more.
+            #[inline]
+            #[must_use]
+            pub fn find_units(&self, needle: &[$uchar]) -> Option<usize> {
+                self.as_ustr().find_units(needle)
+            }
+
+            /// Returns the index of the last occurrence of a sequence of code units in this
+            /// string.
+            ///
+            /// The nul terminator is not included in the search. See
+            /// `rfind_units` on the wide string slice for more.
+            #[inline]
+            #[must_use]
+            pub fn rfind_units(&self, needle: &[$uchar]) -> Option<usize> {
+                self.as_ustr().rfind_units(needle)
+            }
+
+            /// Returns the index of the first occurrence of a code unit in this string.
+            ///
+            /// The nul terminator is not included in the search.
+            #[inline]
+            #[must_use]
+            pub fn position_of(&self, unit: $uchar) -> Option<usize> {
+                self.as_ustr().position_of(unit)
+            }
+
+            /// Returns `true` if this string contains the given code unit.
+            ///
+            /// The nul terminator is not included in the search.
+            #[inline]
+            #[must_use]
+            pub fn contains_unit(&self, unit: $uchar) -> bool {
+                self.as_ustr().contains_unit(unit)
+            }
+
+            /// Returns `true` if the given sequence of code units is a prefix of this string.
+            #[inline]
+            #[must_use]
+            pub fn starts_with(&self, needle: &[$uchar]) -> bool {
+                self.as_ustr().starts_with(needle)
+            }
+
+            /// Returns `true` if the given sequence of code units is a suffix of this string.
+            ///
+            /// The nul terminator is not included in the comparison.
+            #[inline]
+            #[must_use]
+            pub fn ends_with(&self, needle: &[$uchar]) -> bool {
+                self.as_ustr().ends_with(needle)
+            }
+
+            /// Returns an iterator over sub-slices of this string, separated by a sequence of code
+            /// units.
+            ///
+            /// The nul terminator is not included in the sub-slices. See
+            /// `split_units` on the wide string slice for more.
+            #[inline]
+            #[must_use]
+            pub fn split_units<'a>(&'a self, separator: &'a [$uchar]) -> SplitUnits<'a, $uchar> {
+                self.as_ustr().split_units(separator)
+            }
+
             /// Creates a new owned string by repeating this string `n` times.
             ///
             /// # Panics
@@ -1310,6 +1379,29 @@ impl U16CStr {
         crate::platform::os_from_wide(self.as_slice())
     }
 
+    /// Copies the string to an [`OsString`][std::ffi::OsString], preserving unpaired surrogates.
+    ///
+    /// The resulting [`OsString`][std::ffi::OsString] will **not** have a nul terminator. See
+    /// [`U16Str::to_os_string_wtf8`][crate::U16Str::to_os_string_wtf8] for how unpaired
+    /// surrogates are preserved on each platform.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use widestring::{U16CStr, U16CString};
+    /// let wcstr = U16CStr::from_slice(&[0x61, 0xD800, 0]).unwrap();
+    /// let osstr = wcstr.to_os_string_wtf8();
+    ///
+    /// assert_eq!(U16CString::from_os_str_wtf8(&osstr).unwrap(), wcstr);
+    /// ```
+    #[inline]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[must_use]
+    pub fn to_os_string_wtf8(&self) -> std::ffi::OsString {
+        crate::platform::os_from_wide_wtf8(self.as_slice())
+    }
+
     /// Copies the string to a [`String`] if it contains valid UTF-16 data.
     ///
     /// This method assumes this string is encoded as UTF-16 and attempts to decode it as such. It
@@ -1365,6 +1457,33 @@ impl U16CStr {
         String::from_utf16_lossy(self.as_slice())
     }
 
+    /// Encodes the string as UTF-8 into `buf` without allocating, returning the number of bytes
+    /// written.
+    ///
+    /// This method assumes this string is encoded as UTF-16 and replaces unpaired surrogates with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER], the same as
+    /// `to_string_lossy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error reporting the number of bytes required if `buf` is too small. The contents
+    /// of `buf` are unspecified in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use widestring::U16CStr;
+    /// let wstr = U16CStr::from_slice_truncate(&[0x61, 0xD800, 0]).unwrap();
+    /// let mut buf = [0; 8];
+    /// let len = wstr.to_utf8_lossy_into(&mut buf).unwrap();
+    ///
+    /// assert_eq!(&buf[..len], "a\u{FFFD}".as_bytes());
+    /// assert_eq!(wstr.to_utf8_lossy_into(&mut buf[..2]).unwrap_err().required(), 4);
+    /// ```
+    pub fn to_utf8_lossy_into(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        self.as_ustr().to_utf8_lossy_into(buf)
+    }
+
     /// Returns an iterator over the [`char`][prim@char]s of a string slice.
     ///
     /// As this string has no defined encoding, this method assumes the string is UTF-16. Since it
@@ -1429,6 +1548,16 @@ impl U16CStr {
     pub fn char_indices_lossy(&self) -> CharIndicesLossyUtf16<'_> {
         CharIndicesLossyUtf16::new(self.as_slice())
     }
+
+    /// Compares two strings in Unicode code point order.
+    ///
+    /// The nul terminator is not included in the comparison. See
+    /// [`U16Str::cmp_code_point`] for more.
+    #[inline]
+    #[must_use]
+    pub fn cmp_code_point(&self, other: &U16CStr) -> core::cmp::Ordering {
+        self.as_ustr().cmp_code_point(other.as_ustr())
+    }
 }
 
 impl U32CStr {
@@ -1884,6 +2013,33 @@ impl U32CStr {
         self.as_ustr().to_string_lossy()
     }
 
+    /// Encodes the string as UTF-8 into `buf` without allocating, returning the number of bytes
+    /// written.
+    ///
+    /// This method assumes this string is encoded as UTF-32 and replaces invalid code points with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER], the same as
+    /// `to_string_lossy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error reporting the number of bytes required if `buf` is too small. The contents
+    /// of `buf` are unspecified in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use widestring::U32CStr;
+    /// let wstr = U32CStr::from_slice_truncate(&[0x61, 0xD800, 0]).unwrap();
+    /// let mut buf = [0; 8];
+    /// let len = wstr.to_utf8_lossy_into(&mut buf).unwrap();
+    ///
+    /// assert_eq!(&buf[..len], "a\u{FFFD}".as_bytes());
+    /// assert_eq!(wstr.to_utf8_lossy_into(&mut buf[..2]).unwrap_err().required(), 4);
+    /// ```
+    pub fn to_utf8_lossy_into(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        self.as_ustr().to_utf8_lossy_into(buf)
+    }
+
     /// Returns an iterator over the [`char`][prim@char]s of a string slice.
     ///
     /// As this string has no defined encoding, this method assumes the string is UTF-32. Since it
@@ -1138,6 +1138,28 @@ impl U16CString {
         Self::from_vec(v)
     }
 
+    /// Constructs a [`U16CString`] copy from an [`OsStr`][std::ffi::OsStr], decoding unpaired
+    /// surrogates that were encoded by
+    /// [`U16CStr::to_os_string_wtf8`][crate::U16CStr::to_os_string_wtf8] and checking for invalid
+    /// nul values.
+    ///
+    /// See [`U16String::from_os_str_wtf8`][crate::U16String::from_os_str_wtf8] for how the
+    /// [`OsStr`][std::ffi::OsStr] is decoded on each platform. The resulting string will always
+    /// be nul-terminated even if the original string is not.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the data contains a nul value anywhere except the
+    /// last character.
+    /// The returned error will contain a [`Vec<u16>`] as well as the position of the nul value.
+    #[inline]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_os_str_wtf8(s: impl AsRef<std::ffi::OsStr>) -> Result<Self, ContainsNul<u16>> {
+        let v = crate::platform::os_to_wide_wtf8(s.as_ref());
+        Self::from_vec(v)
+    }
+
     /// Constructs a [`U16CString`] copy from an [`OsStr`][std::ffi::OsStr], without checking for nul
     /// values.
     ///
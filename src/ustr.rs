@@ -2,6 +2,15 @@
 //!
 //! This module contains wide string slices and related types.
 
+#[cfg(feature = "alloc")]
+use crate::units_to_bytes;
+use crate::{
+    ascii_lowercase, ascii_uppercase,
+    casefold::uppercase_simple_bmp,
+    cmp_utf16_code_point, encode_chars_into, encode_utf8_unit,
+    error::BufferTooSmall,
+    pattern::{find_seq, rfind_seq},
+};
 #[cfg(feature = "alloc")]
 use crate::{
     error::{Utf16Error, Utf32Error},
@@ -11,7 +20,9 @@ use crate::{
 use alloc::{boxed::Box, string::String, vec::Vec};
 use core::{
     char,
+    cmp::Ordering,
     fmt::Write,
+    hash::{Hash, Hasher},
     ops::{Index, IndexMut, Range},
     slice::{self, SliceIndex},
 };
@@ -30,6 +41,8 @@ macro_rules! ustr_common_impl {
         fn display() -> {}
     } => {
         $(#[$ustr_meta])*
+        // Renamed to `derived_hash_with_manual_eq` after the MSRV
+        #[allow(renamed_and_removed_lints)]
         #[allow(clippy::derive_hash_xor_eq)]
         #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub struct $ustr {
@@ -141,6 +154,36 @@ macro_rules! ustr_common_impl {
                 $ustring::from_vec(&self.inner)
             }
 
+            /// Copies the code units of this string to a new vector of bytes, in little-endian
+            /// byte order.
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            #[inline]
+            #[must_use]
+            pub fn to_le_bytes(&self) -> Vec<u8> {
+                units_to_bytes(self.as_slice(), <$uchar>::to_le_bytes)
+            }
+
+            /// Copies the code units of this string to a new vector of bytes, in big-endian
+            /// byte order.
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            #[inline]
+            #[must_use]
+            pub fn to_be_bytes(&self) -> Vec<u8> {
+                units_to_bytes(self.as_slice(), <$uchar>::to_be_bytes)
+            }
+
+            /// Copies the code units of this string to a new vector of bytes, in native-endian
+            /// byte order.
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            #[inline]
+            #[must_use]
+            pub fn to_ne_bytes(&self) -> Vec<u8> {
+                units_to_bytes(self.as_slice(), <$uchar>::to_ne_bytes)
+            }
+
             /// Converts to a slice of the underlying elements of the string.
             #[inline]
             #[must_use]
@@ -354,6 +397,160 @@ macro_rules! ustr_common_impl {
                 (Self::from_slice_mut(split.0), Self::from_slice_mut(split.1))
             }
 
+            /// Returns the index of the first occurrence of a sequence of code units in this
+            /// string.
+            ///
+            /// Returns [`None`] if the sequence is not found. An empty sequence is always found at
+            /// index `0`.
+            ///
+            /// This does not decode or validate the string in any way, so it works losslessly on
+            /// ill-formed data. Note that because of this, a match is not guaranteed to lie on a
+            /// character boundary if the string is ill-formed.
+            #[inline]
+            #[must_use]
+            pub fn find_units(&self, needle: &[$uchar]) -> Option<usize> {
+                find_seq(self.as_slice(), needle.iter().copied(), needle.len())
+            }
+
+            /// Returns the index of the last occurrence of a sequence of code units in this
+            /// string.
+            ///
+            /// Returns [`None`] if the sequence is not found. An empty sequence is always found at
+            /// index `self.len()`.
+            ///
+            /// This does not decode or validate the string in any way, so it works losslessly on
+            /// ill-formed data.
+            #[inline]
+            #[must_use]
+            pub fn rfind_units(&self, needle: &[$uchar]) -> Option<usize> {
+                rfind_seq(self.as_slice(), needle.iter().copied(), needle.len())
+            }
+
+            /// Returns the index of the first occurrence of a code unit in this string.
+            ///
+            /// Returns [`None`] if the code unit is not found.
+            #[inline]
+            #[must_use]
+            pub fn position_of(&self, unit: $uchar) -> Option<usize> {
+                self.as_slice().iter().position(|&u| u == unit)
+            }
+
+            /// Returns `true` if this string contains the given code unit.
+            #[inline]
+            #[must_use]
+            pub fn contains_unit(&self, unit: $uchar) -> bool {
+                self.as_slice().contains(&unit)
+            }
+
+            /// Returns `true` if the given sequence of code units is a prefix of this string.
+            #[inline]
+            #[must_use]
+            pub fn starts_with(&self, needle: &[$uchar]) -> bool {
+                self.as_slice().starts_with(needle)
+            }
+
+            /// Returns `true` if the given sequence of code units is a suffix of this string.
+            #[inline]
+            #[must_use]
+            pub fn ends_with(&self, needle: &[$uchar]) -> bool {
+                self.as_slice().ends_with(needle)
+            }
+
+            /// Returns an iterator over sub-slices of this string, separated by a sequence of code
+            /// units.
+            ///
+            /// If the sequence is not found, the full string is returned as the only item in the
+            /// iterator. Contiguous separators produce empty sub-slices, and separators at the
+            /// start or end of the string produce empty sub-slices at the start or end of the
+            /// iterator.
+            ///
+            /// This does not decode or validate the string in any way, so it works losslessly on
+            /// ill-formed data.
+            #[inline]
+            #[must_use]
+            pub fn split_units<'a>(&'a self, separator: &'a [$uchar]) -> SplitUnits<'a, $uchar> {
+                SplitUnits::new(self.as_slice(), separator)
+            }
+
+            /// Checks that two strings are an ASCII case-insensitive match.
+            ///
+            /// Same as `to_ascii_lowercase(a) == to_ascii_lowercase(b)`, but without allocating
+            /// and copying temporaries.
+            #[inline]
+            #[must_use]
+            pub fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
+                self.len() == other.len()
+                    && self
+                        .as_slice()
+                        .iter()
+                        .zip(other.as_slice())
+                        .all(|(&a, &b)| ascii_lowercase(a) == ascii_lowercase(b))
+            }
+
+            /// Converts this string to its ASCII upper case equivalent in-place.
+            ///
+            /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', but non-ASCII letters are
+            /// unchanged.
+            ///
+            /// To return a new uppercased value without modifying the existing one, use
+            /// [`to_ascii_uppercase`][Self::to_ascii_uppercase].
+            #[inline]
+            pub fn make_ascii_uppercase(&mut self) {
+                for u in self.as_mut_slice() {
+                    *u = ascii_uppercase(*u);
+                }
+            }
+
+            /// Converts this string to its ASCII lower case equivalent in-place.
+            ///
+            /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', but non-ASCII letters are
+            /// unchanged.
+            ///
+            /// To return a new lowercased value without modifying the existing one, use
+            /// [`to_ascii_lowercase`][Self::to_ascii_lowercase].
+            #[inline]
+            pub fn make_ascii_lowercase(&mut self) {
+                for u in self.as_mut_slice() {
+                    *u = ascii_lowercase(*u);
+                }
+            }
+
+            /// Returns a copy of this string where each character is mapped to its ASCII upper
+            /// case equivalent.
+            ///
+            /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', but non-ASCII letters are
+            /// unchanged.
+            ///
+            /// To uppercase the value in-place, use
+            /// [`make_ascii_uppercase`][Self::make_ascii_uppercase].
+            #[inline]
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            #[must_use]
+            pub fn to_ascii_uppercase(&self) -> $ustring {
+                let mut s = self.to_ustring();
+                s.make_ascii_uppercase();
+                s
+            }
+
+            /// Returns a copy of this string where each character is mapped to its ASCII lower
+            /// case equivalent.
+            ///
+            /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', but non-ASCII letters are
+            /// unchanged.
+            ///
+            /// To lowercase the value in-place, use
+            /// [`make_ascii_lowercase`][Self::make_ascii_lowercase].
+            #[inline]
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            #[must_use]
+            pub fn to_ascii_lowercase(&self) -> $ustring {
+                let mut s = self.to_ustring();
+                s.make_ascii_lowercase();
+                s
+            }
+
             /// Creates a new owned string by repeating this string `n` times.
             ///
             /// # Panics
@@ -785,6 +982,37 @@ impl U16Str {
         crate::platform::os_from_wide(&self.inner)
     }
 
+    /// Copies the string to an [`OsString`][std::ffi::OsString], preserving unpaired surrogates.
+    ///
+    /// This is the same as [`to_os_string`][Self::to_os_string] on Windows. On Unix platforms,
+    /// where [`to_os_string`][Self::to_os_string] replaces unpaired surrogates with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER], this instead encodes
+    /// the string as [WTF-8](https://simonsapin.github.io/wtf-8/), a superset of UTF-8 that can
+    /// also encode unpaired surrogates. The result can be converted back losslessly with
+    /// [`U16String::from_os_str_wtf8`][crate::U16String::from_os_str_wtf8]. Other platforms can
+    /// only store valid Unicode in an [`OsString`][std::ffi::OsString], so unpaired surrogates are
+    /// still replaced.
+    ///
+    /// Valid UTF-16 strings are encoded as regular UTF-8, so the result is the same as
+    /// [`to_os_string`][Self::to_os_string] for them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use widestring::{U16Str, U16String};
+    /// let wstr = U16Str::from_slice(&[0x61, 0xD800, 0x62]);
+    /// let osstr = wstr.to_os_string_wtf8();
+    ///
+    /// assert_eq!(U16String::from_os_str_wtf8(&osstr), wstr);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    #[must_use]
+    pub fn to_os_string_wtf8(&self) -> std::ffi::OsString {
+        crate::platform::os_from_wide_wtf8(&self.inner)
+    }
+
     /// Decodes this string to a [`String`] if it contains valid UTF-16 data.
     ///
     /// This method assumes this string is encoded as UTF-16 and attempts to decode it as such.
@@ -845,6 +1073,53 @@ impl U16Str {
         String::from_utf16_lossy(&self.inner)
     }
 
+    /// Returns the length in bytes of the string encoded as UTF-8.
+    ///
+    /// This method assumes this string is encoded as UTF-16 and counts unpaired surrogates as
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER], so it is the exact
+    /// length of the output of `to_string_lossy` and
+    /// [`to_utf8_lossy_into`][Self::to_utf8_lossy_into]. It does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use widestring::U16Str;
+    /// let wstr = U16Str::from_slice(&[0x61, 0xD800]);
+    ///
+    /// assert_eq!(wstr.utf8_len(), "a\u{FFFD}".len());
+    /// ```
+    #[must_use]
+    pub fn utf8_len(&self) -> usize {
+        self.chars_lossy().map(char::len_utf8).sum()
+    }
+
+    /// Encodes the string as UTF-8 into `buf` without allocating, returning the number of bytes
+    /// written.
+    ///
+    /// This method assumes this string is encoded as UTF-16 and replaces unpaired surrogates with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER], the same as
+    /// `to_string_lossy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error reporting the number of bytes required if `buf` is too small. The contents
+    /// of `buf` are unspecified in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use widestring::U16Str;
+    /// let wstr = U16Str::from_slice(&[0x61, 0xD800]);
+    /// let mut buf = [0; 8];
+    /// let len = wstr.to_utf8_lossy_into(&mut buf).unwrap();
+    ///
+    /// assert_eq!(&buf[..len], "a\u{FFFD}".as_bytes());
+    /// assert_eq!(wstr.to_utf8_lossy_into(&mut buf[..2]).unwrap_err().required(), 4);
+    /// ```
+    pub fn to_utf8_lossy_into(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        encode_chars_into(self.chars_lossy(), buf, encode_utf8_unit)
+    }
+
     /// Returns an iterator over the [`char`][prim@char]s of a string slice.
     ///
     /// As this string has no defined encoding, this method assumes the string is UTF-16. Since it
@@ -909,6 +1184,53 @@ impl U16Str {
     pub fn char_indices_lossy(&self) -> CharIndicesLossyUtf16<'_> {
         CharIndicesLossyUtf16::new(self.as_slice())
     }
+
+    /// Compares two strings ordinally, ignoring case.
+    ///
+    /// This is equivalent to `CompareStringOrdinal` with `bIgnoreCase` set to `TRUE` on Windows,
+    /// which is how NTFS file names and registry keys are compared. The strings are compared code
+    /// unit by code unit, after mapping each code unit to its simple upper case equivalent. Code
+    /// units that have no simple upper case mapping, including all surrogates, are compared
+    /// unchanged, so this never fails on ill-formed UTF-16.
+    ///
+    /// To use this comparison in collections such as maps and sets, wrap the strings in
+    /// [`CaseInsensitive`].
+    #[must_use]
+    pub fn cmp_ordinal_ignore_case(&self, other: &U16Str) -> Ordering {
+        self.as_slice()
+            .iter()
+            .map(|&u| uppercase_simple_bmp(u))
+            .cmp(other.as_slice().iter().map(|&u| uppercase_simple_bmp(u)))
+    }
+
+    /// Checks that two strings are equal when compared ordinally, ignoring case.
+    ///
+    /// See [`cmp_ordinal_ignore_case`][Self::cmp_ordinal_ignore_case] for details of the
+    /// comparison.
+    #[must_use]
+    pub fn eq_ordinal_ignore_case(&self, other: &U16Str) -> bool {
+        self.len() == other.len()
+            && self
+                .as_slice()
+                .iter()
+                .zip(other.as_slice())
+                .all(|(&a, &b)| a == b || uppercase_simple_bmp(a) == uppercase_simple_bmp(b))
+    }
+
+    /// Compares two strings in Unicode code point order.
+    ///
+    /// The [`Ord`] implementation of [`U16Str`] compares code units, which sorts supplementary
+    /// characters (encoded as surrogate pairs) before the characters `U+E000` to `U+FFFF`. This
+    /// method instead sorts strings in the same order as the equivalent [`str`] or [`U32Str`]
+    /// values. Unpaired surrogates are ordered like the surrogates of supplementary characters,
+    /// so they sort after every character up to `U+FFFF`, including `U+E000` to `U+FFFF`.
+    ///
+    /// To use this ordering in sorted collections, wrap the strings in [`CodePointOrd`].
+    #[inline]
+    #[must_use]
+    pub fn cmp_code_point(&self, other: &U16Str) -> Ordering {
+        cmp_utf16_code_point(self.as_slice(), other.as_slice())
+    }
 }
 
 impl U32Str {
@@ -1090,6 +1412,53 @@ impl U32Str {
         unsafe { String::from_utf8_unchecked(vec) }
     }
 
+    /// Returns the length in bytes of the string encoded as UTF-8.
+    ///
+    /// This method assumes this string is encoded as UTF-32 and counts invalid code points as
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER], so it is the exact
+    /// length of the output of `to_string_lossy` and
+    /// [`to_utf8_lossy_into`][Self::to_utf8_lossy_into]. It does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use widestring::U32Str;
+    /// let wstr = U32Str::from_slice(&[0x61, 0xD800]);
+    ///
+    /// assert_eq!(wstr.utf8_len(), "a\u{FFFD}".len());
+    /// ```
+    #[must_use]
+    pub fn utf8_len(&self) -> usize {
+        self.chars_lossy().map(char::len_utf8).sum()
+    }
+
+    /// Encodes the string as UTF-8 into `buf` without allocating, returning the number of bytes
+    /// written.
+    ///
+    /// This method assumes this string is encoded as UTF-32 and replaces invalid code points with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER], the same as
+    /// `to_string_lossy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error reporting the number of bytes required if `buf` is too small. The contents
+    /// of `buf` are unspecified in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use widestring::U32Str;
+    /// let wstr = U32Str::from_slice(&[0x61, 0xD800]);
+    /// let mut buf = [0; 8];
+    /// let len = wstr.to_utf8_lossy_into(&mut buf).unwrap();
+    ///
+    /// assert_eq!(&buf[..len], "a\u{FFFD}".as_bytes());
+    /// assert_eq!(wstr.to_utf8_lossy_into(&mut buf[..2]).unwrap_err().required(), 4);
+    /// ```
+    pub fn to_utf8_lossy_into(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        encode_chars_into(self.chars_lossy(), buf, encode_utf8_unit)
+    }
+
     /// Returns an iterator over the [`char`][prim@char]s of a string slice.
     ///
     /// As this string has no defined encoding, this method assumes the string is UTF-32. Since it
@@ -1246,3 +1615,276 @@ impl<'a> core::fmt::Display for Display<'a, U32Str> {
         Ok(())
     }
 }
+
+/// A wrapper around a UTF-16 wide string that compares, orders, and hashes ordinally, ignoring
+/// case.
+///
+/// All comparisons use [`U16Str::cmp_ordinal_ignore_case`], which matches how Windows compares
+/// file names and registry keys. [`Hash`] is implemented consistently with [`Eq`], so this can be
+/// used as the key of a `HashMap` or `BTreeMap` to look up strings the same way Windows does.
+///
+/// The wrapped value can be any type that can be referenced as a [`U16Str`], such as
+/// `&U16Str`, [`U16String`][crate::U16String] or [`U16CString`][crate::U16CString].
+///
+/// # Examples
+///
+/// ```
+/// use widestring::{u16str, ustr::CaseInsensitive};
+///
+/// let a = CaseInsensitive(u16str!("HKEY_LOCAL_MACHINE\\Software"));
+/// let b = CaseInsensitive(u16str!("hkey_local_machine\\SOFTWARE"));
+/// assert_eq!(a, b);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CaseInsensitive<T>(pub T);
+
+impl<T: AsRef<U16Str>> PartialEq for CaseInsensitive<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_ref().eq_ordinal_ignore_case(other.0.as_ref())
+    }
+}
+
+impl<T: AsRef<U16Str>> Eq for CaseInsensitive<T> {}
+
+impl<T: AsRef<U16Str>> PartialOrd for CaseInsensitive<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AsRef<U16Str>> Ord for CaseInsensitive<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.as_ref().cmp_ordinal_ignore_case(other.0.as_ref())
+    }
+}
+
+impl<T: AsRef<U16Str>> Hash for CaseInsensitive<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let s = self.0.as_ref();
+        state.write_usize(s.len());
+        for &u in s.as_slice() {
+            state.write_u16(uppercase_simple_bmp(u));
+        }
+    }
+}
+
+/// A wrapper around a UTF-16 wide string that orders strings in Unicode code point order.
+///
+/// All comparisons use [`U16Str::cmp_code_point`], so that sorted collections of UTF-16 strings
+/// are in the same order as the same strings held as [`str`] or [`U32Str`]. Equality and hashing
+/// are the same as those of [`U16Str`].
+///
+/// The wrapped value can be any type that can be referenced as a [`U16Str`], such as
+/// `&U16Str`, [`U16String`][crate::U16String] or [`Utf16String`][crate::Utf16String].
+///
+/// # Examples
+///
+/// ```
+/// use widestring::{u16str, ustr::CodePointOrd};
+///
+/// let mut v = vec![CodePointOrd(u16str!("\u{FF61}")), CodePointOrd(u16str!("\u{10000}"))];
+/// v.sort();
+/// assert_eq!(v[0].0, u16str!("\u{FF61}"));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CodePointOrd<T>(pub T);
+
+impl<T: AsRef<U16Str>> PartialEq for CodePointOrd<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_ref() == other.0.as_ref()
+    }
+}
+
+impl<T: AsRef<U16Str>> Eq for CodePointOrd<T> {}
+
+impl<T: AsRef<U16Str>> PartialOrd for CodePointOrd<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AsRef<U16Str>> Ord for CodePointOrd<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.as_ref().cmp_code_point(other.0.as_ref())
+    }
+}
+
+impl<T: AsRef<U16Str>> Hash for CodePointOrd<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ref().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CaseInsensitive, CodePointOrd};
+    use crate::*;
+
+    #[test]
+    fn find_units() {
+        let s = u16str!("abcabc");
+        assert_eq!(s.find_units(&[b'b' as u16, b'c' as u16]), Some(1));
+        assert_eq!(s.rfind_units(&[b'b' as u16, b'c' as u16]), Some(4));
+        assert_eq!(s.find_units(&[b'x' as u16]), None);
+        assert_eq!(s.find_units(&[]), Some(0));
+        assert_eq!(s.position_of(b'c' as u16), Some(2));
+        assert!(s.contains_unit(b'a' as u16));
+        assert!(s.starts_with(&[b'a' as u16, b'b' as u16]));
+        assert!(s.ends_with(&[b'b' as u16, b'c' as u16]));
+        assert!(!s.ends_with(&[b'a' as u16]));
+
+        // Unpaired surrogates are searched losslessly
+        let s = U16Str::from_slice(&[0x61, 0xD800, 0x62]);
+        assert_eq!(s.find_units(&[0xD800]), Some(1));
+
+        let s = u32cstr!("abc");
+        assert_eq!(s.find_units(&[b'c' as u32]), Some(2));
+        assert!(!s.contains_unit(0));
+    }
+
+    #[test]
+    fn split_units() {
+        let s = u16str!("a,b,,c");
+        let sep = [b',' as u16];
+        let mut iter = s.split_units(&sep);
+        assert_eq!(iter.next(), Some(u16str!("a")));
+        assert_eq!(iter.next(), Some(u16str!("b")));
+        assert_eq!(iter.next(), Some(u16str!("")));
+        assert_eq!(iter.next(), Some(u16str!("c")));
+        assert_eq!(iter.next(), None);
+
+        let s = u32str!("ab");
+        let mut iter = s.split_units(&[]);
+        assert_eq!(iter.next(), Some(u32str!("")));
+        assert_eq!(iter.next(), Some(u32str!("a")));
+        assert_eq!(iter.next(), Some(u32str!("b")));
+        assert_eq!(iter.next(), Some(u32str!("")));
+        assert_eq!(iter.next(), None);
+
+        let s = u16cstr!(",x,");
+        assert_eq!(s.split_units(&sep).count(), 3);
+    }
+
+    #[test]
+    fn ascii_case() {
+        // Ill-formed data is left untouched
+        let mut buf = [b'a' as u16, 0xD800, b'Z' as u16, 0xDC00];
+        let s = U16Str::from_slice_mut(&mut buf);
+        s.make_ascii_uppercase();
+        assert_eq!(s.as_slice(), &[b'A' as u16, 0xD800, b'Z' as u16, 0xDC00]);
+        s.make_ascii_lowercase();
+        assert_eq!(s.as_slice(), &[b'a' as u16, 0xD800, b'z' as u16, 0xDC00]);
+        assert!(s.eq_ignore_ascii_case(U16Str::from_slice(&[
+            b'A' as u16,
+            0xD800,
+            b'z' as u16,
+            0xDC00
+        ])));
+        assert!(!u32str!("abc").eq_ignore_ascii_case(u32str!("ab")));
+    }
+
+    #[test]
+    fn ordinal_ignore_case() {
+        use core::cmp::Ordering;
+
+        let a = u16str!("C:\\Users\\Ärger.TXT");
+        let b = u16str!("c:\\users\\ärger.txt");
+        assert!(a.eq_ordinal_ignore_case(b));
+        assert_eq!(a.cmp_ordinal_ignore_case(b), Ordering::Equal);
+        assert!(!u16str!("straße").eq_ordinal_ignore_case(u16str!("STRASSE")));
+        assert_eq!(
+            u16str!("a").cmp_ordinal_ignore_case(u16str!("B")),
+            Ordering::Less
+        );
+        assert_eq!(
+            u16str!("_").cmp_ordinal_ignore_case(u16str!("a")),
+            Ordering::Greater
+        );
+
+        // Unpaired surrogates compare unchanged
+        let a = U16Str::from_slice(&[0xD800, b'x' as u16]);
+        let b = U16Str::from_slice(&[0xD800, b'X' as u16]);
+        assert!(a.eq_ordinal_ignore_case(b));
+        assert_eq!(CaseInsensitive(a), CaseInsensitive(b));
+    }
+
+    #[test]
+    fn ordinal_ignore_case_simple_mapping() {
+        // Characters whose full upper case mapping expands still have a simple upper case mapping
+        assert!(u16str!("ᾀ").eq_ordinal_ignore_case(u16str!("ᾈ")));
+        assert!(u16str!("ᾯ").eq_ordinal_ignore_case(u16str!("ᾧ")));
+        assert!(u16str!("ᾳῃῳ").eq_ordinal_ignore_case(u16str!("ᾼῌῼ")));
+        assert_eq!(CaseInsensitive(u16str!("ᾀ")), CaseInsensitive(u16str!("ᾈ")));
+        assert!(!u16str!("ᾀ").eq_ordinal_ignore_case(u16str!("ἈΙ")));
+        assert!(!u16str!("ß").eq_ordinal_ignore_case(u16str!("ẞ")));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn case_insensitive_map() {
+        use std::collections::HashMap;
+
+        let mut map = HashMap::new();
+        map.insert(CaseInsensitive(u16str!("Software")), 1);
+        map.insert(CaseInsensitive(u16str!("SOFTWARE")), 2);
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get(&CaseInsensitive(u16str!("software"))), Some(&2));
+    }
+
+    #[test]
+    fn code_point_order() {
+        use core::cmp::Ordering;
+
+        let bmp = u16str!("\u{FF61}");
+        let supplementary = u16str!("\u{10000}");
+        assert_eq!(bmp.cmp(supplementary), Ordering::Greater);
+        assert_eq!(bmp.cmp_code_point(supplementary), Ordering::Less);
+        assert_eq!(
+            u16str!("a\u{E000}").cmp_code_point(u16str!("a\u{D7FF}")),
+            Ordering::Greater
+        );
+        assert_eq!(
+            u16str!("ab").cmp_code_point(u16str!("a")),
+            Ordering::Greater
+        );
+        assert!(CodePointOrd(bmp) < CodePointOrd(supplementary));
+        assert!(CodePointOrd(u16str!("\u{D7FF}")) < CodePointOrd(supplementary));
+
+        // Unpaired surrogates sort after all BMP characters
+        let lone = U16Str::from_slice(&[0xD800]);
+        assert_eq!(lone.cmp_code_point(u16str!("\u{E000}")), Ordering::Greater);
+        assert_eq!(lone.cmp_code_point(u16str!("\u{FFFF}")), Ordering::Greater);
+        assert_eq!(lone.cmp_code_point(supplementary), Ordering::Less);
+    }
+
+    #[test]
+    fn to_utf8_lossy_into() {
+        let s = U16Str::from_slice(&[0x61, 0xD800, 0xD83D, 0xDC96]);
+        let mut buf = [0; 8];
+        assert_eq!(s.to_utf8_lossy_into(&mut buf), Ok(8));
+        assert_eq!(&buf, "a\u{FFFD}\u{1F496}".as_bytes());
+        assert_eq!(
+            s.to_utf8_lossy_into(&mut buf[..4]).unwrap_err().required(),
+            8
+        );
+
+        let s = U32Str::from_slice(&[0x61, 0x110000]);
+        assert_eq!(s.to_utf8_lossy_into(&mut buf), Ok(4));
+        assert_eq!(&buf[..4], "a\u{FFFD}".as_bytes());
+    }
+
+    #[test]
+    fn utf8_len_lossy() {
+        let s = U16Str::from_slice(&[0x61, 0xDC00, 0xD83D, 0xDC96, 0xD800]);
+        assert_eq!(s.utf8_len(), "a\u{FFFD}\u{1F496}\u{FFFD}".len());
+        let s = U32Str::from_slice(&[0x61, 0xD800, 0x1F496, 0x110000]);
+        assert_eq!(s.utf8_len(), "a\u{FFFD}\u{1F496}\u{FFFD}".len());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     error::{DecodeUtf16Error, DecodeUtf32Error},
     iter::{DecodeUtf16, DecodeUtf16Lossy, DecodeUtf32, DecodeUtf32Lossy},
+    pattern::find_seq,
 };
 use core::{
     iter::{Copied, DoubleEndedIterator, ExactSizeIterator, FusedIterator},
@@ -486,3 +487,76 @@ impl<'a> ExactSizeIterator for CharIndicesLossyUtf32<'a> {
         self.iter.len()
     }
 }
+
+/// An iterator over sub-slices of a wide string slice, separated by a sequence of code units.
+///
+/// This struct is created by the `split_units` method on strings. See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct SplitUnits<'a, C> {
+    haystack: &'a [C],
+    needle: &'a [C],
+    start: usize,
+    position: Option<usize>,
+    finished: bool,
+}
+
+impl<'a, C> SplitUnits<'a, C> {
+    pub(crate) fn new(haystack: &'a [C], needle: &'a [C]) -> Self {
+        Self {
+            haystack,
+            needle,
+            start: 0,
+            position: Some(0),
+            finished: false,
+        }
+    }
+}
+
+impl<'a, C: Copy + PartialEq> SplitUnits<'a, C> {
+    fn next_slice(&mut self) -> Option<&'a [C]> {
+        if self.finished {
+            return None;
+        }
+        if let Some(position) = self.position {
+            let needle = self.needle;
+            let haystack = &self.haystack[position..];
+            if let Some(i) = find_seq(haystack, needle.iter().copied(), needle.len()) {
+                let (a, b) = (position + i, position + i + needle.len());
+                let piece = &self.haystack[self.start..a];
+                self.start = b;
+                self.position = if a == b {
+                    // Empty needle, so resume searching after the next code unit
+                    Some(b + 1).filter(|&p| p <= self.haystack.len())
+                } else {
+                    Some(b)
+                };
+                return Some(piece);
+            }
+            self.position = None;
+        }
+        self.finished = true;
+        Some(&self.haystack[self.start..])
+    }
+}
+
+impl<'a> Iterator for SplitUnits<'a, u16> {
+    type Item = &'a crate::U16Str;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_slice().map(crate::U16Str::from_slice)
+    }
+}
+
+impl<'a> FusedIterator for SplitUnits<'a, u16> {}
+
+impl<'a> Iterator for SplitUnits<'a, u32> {
+    type Item = &'a crate::U32Str;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_slice().map(crate::U32Str::from_slice)
+    }
+}
+
+impl<'a> FusedIterator for SplitUnits<'a, u32> {}
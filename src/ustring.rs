@@ -2,7 +2,10 @@
 //!
 //! This module contains wide strings and related types.
 
-use crate::{U16CStr, U16CString, U16Str, U32CStr, U32CString, U32Str};
+use crate::{
+    error::InvalidByteLength, units_from_bytes, U16CStr, U16CString, U16Str, U32CStr, U32CString,
+    U32Str,
+};
 use alloc::{
     borrow::{Cow, ToOwned},
     boxed::Box,
@@ -86,6 +89,45 @@ macro_rules! ustring_common_impl {
                 Self { inner: raw.into() }
             }
 
+            /// Constructs a wide string from bytes holding code units in little-endian byte order.
+            ///
+            /// No checks are made on the contents of the code units.
+            ///
+            /// # Errors
+            ///
+            /// This function will return an error if the length of `bytes` is not a multiple of
+            /// the code unit size.
+            #[inline]
+            pub fn from_le_bytes(bytes: &[u8]) -> Result<Self, InvalidByteLength> {
+                units_from_bytes(bytes, <$uchar>::from_le_bytes).map(Self::from_vec)
+            }
+
+            /// Constructs a wide string from bytes holding code units in big-endian byte order.
+            ///
+            /// No checks are made on the contents of the code units.
+            ///
+            /// # Errors
+            ///
+            /// This function will return an error if the length of `bytes` is not a multiple of
+            /// the code unit size.
+            #[inline]
+            pub fn from_be_bytes(bytes: &[u8]) -> Result<Self, InvalidByteLength> {
+                units_from_bytes(bytes, <$uchar>::from_be_bytes).map(Self::from_vec)
+            }
+
+            /// Constructs a wide string from bytes holding code units in native-endian byte order.
+            ///
+            /// No checks are made on the contents of the code units.
+            ///
+            /// # Errors
+            ///
+            /// This function will return an error if the length of `bytes` is not a multiple of
+            /// the code unit size.
+            #[inline]
+            pub fn from_ne_bytes(bytes: &[u8]) -> Result<Self, InvalidByteLength> {
+                units_from_bytes(bytes, <$uchar>::from_ne_bytes).map(Self::from_vec)
+            }
+
             /// Constructs a wide string copy from a pointer and a length.
             ///
             /// The `len` argument is the number of elements, **not** the number of bytes.
@@ -1219,6 +1261,35 @@ impl U16String {
         }
     }
 
+    /// Constructs a [`U16String`] copy from an [`OsStr`][std::ffi::OsStr], decoding unpaired
+    /// surrogates that were encoded by
+    /// [`U16Str::to_os_string_wtf8`][crate::U16Str::to_os_string_wtf8].
+    ///
+    /// This is the same as [`from_os_str`][Self::from_os_str] on Windows. On Unix platforms, the
+    /// bytes of the [`OsStr`][std::ffi::OsStr] are decoded as
+    /// [WTF-8](https://simonsapin.github.io/wtf-8/), a superset of UTF-8 that can also encode
+    /// unpaired surrogates, and any invalid bytes are replaced with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use widestring::U16String;
+    /// let wstr = U16String::from_vec(vec![0x61, 0xDC00]);
+    /// let osstr = wstr.to_os_string_wtf8();
+    ///
+    /// assert_eq!(U16String::from_os_str_wtf8(&osstr), wstr);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    #[must_use]
+    pub fn from_os_str_wtf8<S: AsRef<std::ffi::OsStr> + ?Sized>(s: &S) -> Self {
+        Self {
+            inner: crate::platform::os_to_wide_wtf8(s.as_ref()),
+        }
+    }
+
     /// Extends the string with the given string slice, encoding it at UTF-16.
     ///
     /// No checks are performed on the strings. It is possible to end up nul values inside the
@@ -1579,4 +1650,42 @@ mod test {
         write!(s, "{}", 1234).unwrap();
         assert_eq!(s, U16String::from_str("1234"));
     }
+
+    #[test]
+    fn from_bytes() {
+        let s = U16String::from_le_bytes(&[0x61, 0, 0x00, 0xD8]).unwrap();
+        assert_eq!(s.as_slice(), &[0x61, 0xD800]);
+        assert_eq!(s.to_le_bytes(), [0x61, 0, 0x00, 0xD8]);
+        assert_eq!(s.to_be_bytes(), [0, 0x61, 0xD8, 0x00]);
+        assert_eq!(U16String::from_be_bytes(&s.to_be_bytes()).unwrap(), s);
+        assert_eq!(U16String::from_ne_bytes(&s.to_ne_bytes()).unwrap(), s);
+
+        let err = U16String::from_le_bytes(&[0x61, 0, 0x62]).unwrap_err();
+        assert_eq!(err.byte_len(), 3);
+        assert_eq!(err.unit_size(), 2);
+
+        let s = U32String::from_be_bytes(&[0, 0x01, 0xF4, 0x96]).unwrap();
+        assert_eq!(s.as_slice(), &[0x1F496]);
+        assert_eq!(s.to_le_bytes(), [0x96, 0xF4, 0x01, 0]);
+        assert!(U32String::from_le_bytes(&[0; 6]).is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", unix))]
+    fn os_string_wtf8() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let s = U16String::from_vec([0xD83D, 0xDC96, 0xDC96, 0x61, 0xD83D]);
+        let os = s.to_os_string_wtf8();
+        assert_eq!(os.as_bytes(), b"\xF0\x9F\x92\x96\xED\xB2\x96a\xED\xA0\xBD");
+        assert_eq!(U16String::from_os_str_wtf8(&os), s);
+        assert_eq!(
+            U16String::from_os_str_wtf8(std::ffi::OsStr::from_bytes(b"a\xFF\xED\xA0")),
+            U16String::from_vec([0x61, 0xFFFD, 0xFFFD])
+        );
+        assert_eq!(
+            U16String::from_os_str_wtf8("a💖"),
+            U16String::from_str("a💖")
+        );
+    }
 }
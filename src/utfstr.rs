@@ -2,16 +2,21 @@
 //!
 //! This module contains UTF string slices and related types.
 
+#[cfg(feature = "normalization")]
+use crate::iter::{Nfc, Nfd, Nfkc, Nfkd};
 use crate::{
-    error::{Utf16Error, Utf32Error},
+    ascii_lowercase, ascii_uppercase, cmp_utf16_code_point, encode_chars_into, encode_utf16_unit,
+    encode_utf32_unit, encode_utf8_unit,
+    error::{BufferTooSmall, Utf16Error, Utf32Error},
     is_utf16_low_surrogate,
-    iter::{EncodeUtf16, EncodeUtf32, EncodeUtf8},
+    iter::{CaseFold, CaseFoldSimple, EncodeUtf16, EncodeUtf32, EncodeUtf8},
+    pattern::Pattern,
     validate_utf16, validate_utf32, U16Str, U32Str,
 };
 #[cfg(feature = "alloc")]
-use crate::{Utf16String, Utf32String};
+use crate::{units_to_bytes, Utf16String, Utf32String};
 #[cfg(feature = "alloc")]
-use alloc::{borrow::Cow, boxed::Box, string::String};
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
 use core::{
     convert::{AsMut, AsRef, TryFrom},
     fmt::Write,
@@ -45,6 +50,8 @@ macro_rules! utfstr_common_impl {
         fn len() -> {}
     } => {
         $(#[$utfstr_meta])*
+        // Renamed to `derived_hash_with_manual_eq` after the MSRV
+        #[allow(renamed_and_removed_lints)]
         #[allow(clippy::derive_hash_xor_eq)]
         #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub struct $utfstr {
@@ -214,6 +221,505 @@ macro_rules! utfstr_common_impl {
                 }
             }
 
+            /// Returns a string slice with all prefixes and suffixes that match a pattern
+            /// repeatedly removed.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[must_use]
+            pub fn trim_matches<P: Pattern<Self>>(&self, mut pat: P) -> &Self {
+                let mut s = self;
+                while let Some(len) = pat.match_prefix(s).filter(|&len| len > 0) {
+                    s = &s[len..];
+                }
+                while let Some(len) = pat.match_suffix(s).filter(|&len| len > 0) {
+                    s = &s[..s.len() - len];
+                }
+                s
+            }
+
+            /// Returns a string slice with all prefixes that match a pattern repeatedly removed.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            ///
+            /// # Text directionality
+            ///
+            /// A string is a sequence of elements. `start` in this context means the first position
+            /// of that sequence; for a left-to-right language like English or Russian, this will be
+            /// left side, and for right-to-left languages like Arabic or Hebrew, this will be the
+            /// right side.
+            #[must_use]
+            pub fn trim_start_matches<P: Pattern<Self>>(&self, mut pat: P) -> &Self {
+                let mut s = self;
+                while let Some(len) = pat.match_prefix(s).filter(|&len| len > 0) {
+                    s = &s[len..];
+                }
+                s
+            }
+
+            /// Returns a string slice with all suffixes that match a pattern repeatedly removed.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            ///
+            /// # Text directionality
+            ///
+            /// A string is a sequence of elements. `end` in this context means the last position of
+            /// that sequence; for a left-to-right language like English or Russian, this will be
+            /// right side, and for right-to-left languages like Arabic or Hebrew, this will be the
+            /// left side.
+            #[must_use]
+            pub fn trim_end_matches<P: Pattern<Self>>(&self, mut pat: P) -> &Self {
+                let mut s = self;
+                while let Some(len) = pat.match_suffix(s).filter(|&len| len > 0) {
+                    s = &s[..s.len() - len];
+                }
+                s
+            }
+
+            /// Returns a string slice with the prefix removed.
+            ///
+            /// If the string slice starts with the pattern `prefix`, returns the substring after
+            /// the prefix, wrapped in [`Some`]. Unlike
+            /// [`trim_start_matches`][Self::trim_start_matches], this method removes the prefix
+            /// exactly once.
+            ///
+            /// If the string slice does not start with `prefix`, returns [`None`].
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[inline]
+            #[must_use]
+            pub fn strip_prefix<P: Pattern<Self>>(&self, mut prefix: P) -> Option<&Self> {
+                let len = prefix.match_prefix(self)?;
+                Some(&self[len..])
+            }
+
+            /// Returns a string slice with the suffix removed.
+            ///
+            /// If the string slice ends with the pattern `suffix`, returns the substring before
+            /// the suffix, wrapped in [`Some`]. Unlike
+            /// [`trim_end_matches`][Self::trim_end_matches], this method removes the suffix
+            /// exactly once.
+            ///
+            /// If the string slice does not end with `suffix`, returns [`None`].
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[inline]
+            #[must_use]
+            pub fn strip_suffix<P: Pattern<Self>>(&self, mut suffix: P) -> Option<&Self> {
+                let len = suffix.match_suffix(self)?;
+                Some(&self[..self.len() - len])
+            }
+
+            /// Returns `true` if the given pattern matches a sub-slice of this string slice.
+            ///
+            /// Returns `false` if it does not.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[inline]
+            #[must_use]
+            pub fn contains<P: Pattern<Self>>(&self, pat: P) -> bool {
+                pat.is_contained_in(self)
+            }
+
+            /// Returns `true` if the given pattern matches a prefix of this string slice.
+            ///
+            /// Returns `false` if it does not.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[inline]
+            #[must_use]
+            pub fn starts_with<P: Pattern<Self>>(&self, pat: P) -> bool {
+                pat.is_prefix_of(self)
+            }
+
+            /// Returns `true` if the given pattern matches a suffix of this string slice.
+            ///
+            /// Returns `false` if it does not.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[inline]
+            #[must_use]
+            pub fn ends_with<P: Pattern<Self>>(&self, pat: P) -> bool {
+                pat.is_suffix_of(self)
+            }
+
+            /// Returns the index of the first character of this string slice that matches the
+            /// pattern.
+            ///
+            /// Returns [`None`] if the pattern doesn't match.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            ///
+            /// The returned index is an offset in code units, and is always on a [`char`]
+            /// boundary, so it can be used to index or split this string slice.
+            #[inline]
+            #[must_use]
+            pub fn find<P: Pattern<Self>>(&self, mut pat: P) -> Option<usize> {
+                pat.find_in(self).map(|(start, _)| start)
+            }
+
+            /// Returns the index of the first character of the last match of the pattern in this
+            /// string slice.
+            ///
+            /// Returns [`None`] if the pattern doesn't match.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            ///
+            /// The returned index is an offset in code units, and is always on a [`char`]
+            /// boundary, so it can be used to index or split this string slice.
+            #[inline]
+            #[must_use]
+            pub fn rfind<P: Pattern<Self>>(&self, mut pat: P) -> Option<usize> {
+                pat.rfind_in(self).map(|(start, _)| start)
+            }
+
+            /// Returns an iterator over substrings of this string slice, separated by characters
+            /// matched by a pattern.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            ///
+            /// If there are no matches the full string slice is returned as the only item in the
+            /// iterator. Contiguous separators produce empty substrings, and separators at the
+            /// start or end of the string slice produce empty substrings at the start or end of the
+            /// iterator.
+            ///
+            /// To iterate from the end of the string slice, use [`rsplit`][Self::rsplit] instead.
+            #[inline]
+            #[must_use]
+            pub fn split<P: Pattern<Self>>(&self, pat: P) -> Split<'_, Self, P> {
+                Split::new(self, pat, self.len())
+            }
+
+            /// Returns an iterator over substrings of this string slice, separated by characters
+            /// matched by a pattern and yielded in reverse order.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            ///
+            /// This behaves the same as [`split`][Self::split], but searches from the end of the
+            /// string slice.
+            #[inline]
+            #[must_use]
+            pub fn rsplit<P: Pattern<Self>>(&self, pat: P) -> RSplit<'_, Self, P> {
+                RSplit::new(self, pat, self.len())
+            }
+
+            /// Returns an iterator over substrings of this string slice, separated by a pattern,
+            /// restricted to returning at most `n` items.
+            ///
+            /// If `n` substrings are returned, the last substring (the `n`th substring) will
+            /// contain the remainder of the string slice.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[inline]
+            #[must_use]
+            pub fn splitn<P: Pattern<Self>>(&self, n: usize, pat: P) -> SplitN<'_, Self, P> {
+                SplitN::new(self, pat, self.len(), n)
+            }
+
+            /// Returns an iterator over substrings of this string slice, separated by a pattern,
+            /// starting from the end of the string slice and restricted to returning at most `n`
+            /// items.
+            ///
+            /// If `n` substrings are returned, the last substring (the `n`th substring) will
+            /// contain the remainder of the string slice.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[inline]
+            #[must_use]
+            pub fn rsplitn<P: Pattern<Self>>(&self, n: usize, pat: P) -> RSplitN<'_, Self, P> {
+                RSplitN::new(self, pat, self.len(), n)
+            }
+
+            /// Returns an iterator over substrings of this string slice, separated by characters
+            /// matched by a pattern.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            ///
+            /// Equivalent to [`split`][Self::split], except that the trailing substring is skipped
+            /// if empty. This is useful for data that is terminated, rather than separated, by the
+            /// pattern.
+            #[inline]
+            #[must_use]
+            pub fn split_terminator<P: Pattern<Self>>(
+                &self,
+                pat: P,
+            ) -> SplitTerminator<'_, Self, P> {
+                SplitTerminator::new(self, pat, self.len())
+            }
+
+            /// Returns an iterator over substrings of this string slice, separated by characters
+            /// matched by a pattern.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            ///
+            /// Differs from the iterator produced by [`split`][Self::split] in that
+            /// `split_inclusive` leaves the matched part as the terminator of the substring. If the
+            /// last element of the string slice is matched, that element will be considered the
+            /// terminator of the preceding substring, and no trailing empty substring is returned.
+            #[inline]
+            #[must_use]
+            pub fn split_inclusive<P: Pattern<Self>>(&self, pat: P) -> SplitInclusive<'_, Self, P> {
+                SplitInclusive::new(self, pat, self.len())
+            }
+
+            /// Splits this string slice on the first occurrence of the specified delimiter and
+            /// returns the prefix before the delimiter and the suffix after the delimiter.
+            ///
+            /// Returns [`None`] if the delimiter is not found.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[inline]
+            #[must_use]
+            pub fn split_once<P: Pattern<Self>>(&self, mut delimiter: P) -> Option<(&Self, &Self)> {
+                let (start, end) = delimiter.find_in(self)?;
+                Some((&self[..start], &self[end..]))
+            }
+
+            /// Splits this string slice on the last occurrence of the specified delimiter and
+            /// returns the prefix before the delimiter and the suffix after the delimiter.
+            ///
+            /// Returns [`None`] if the delimiter is not found.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[inline]
+            #[must_use]
+            pub fn rsplit_once<P: Pattern<Self>>(
+                &self,
+                mut delimiter: P,
+            ) -> Option<(&Self, &Self)> {
+                let (start, end) = delimiter.rfind_in(self)?;
+                Some((&self[..start], &self[end..]))
+            }
+
+            /// Returns an iterator over the disjoint matches of a pattern within this string
+            /// slice.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            ///
+            /// To count the number of occurrences of a pattern, use `matches(pat).count()`.
+            #[inline]
+            #[must_use]
+            pub fn matches<P: Pattern<Self>>(&self, pat: P) -> Matches<'_, Self, P> {
+                Matches::new(self, pat, self.len())
+            }
+
+            /// Returns an iterator over the disjoint matches of a pattern within this string
+            /// slice, yielded in reverse order.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[inline]
+            #[must_use]
+            pub fn rmatches<P: Pattern<Self>>(&self, pat: P) -> RMatches<'_, Self, P> {
+                RMatches::new(self, pat, self.len())
+            }
+
+            /// Returns an iterator over the disjoint matches of a pattern within this string slice
+            /// as well as the index that the match starts at.
+            ///
+            /// For matches of `pat` within `self` that overlap, only the indices corresponding to
+            /// the first match are returned.
+            ///
+            /// The index is an offset in code units, and is always on a [`char`] boundary.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[inline]
+            #[must_use]
+            pub fn match_indices<P: Pattern<Self>>(&self, pat: P) -> MatchIndices<'_, Self, P> {
+                MatchIndices::new(self, pat, self.len())
+            }
+
+            /// Returns an iterator over the disjoint matches of a pattern within this string slice
+            /// as well as the index that the match starts at, yielded in reverse order.
+            ///
+            /// For matches of `pat` within `self` that overlap, only the indices corresponding to
+            /// the last match are returned.
+            ///
+            /// The index is an offset in code units, and is always on a [`char`] boundary.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[inline]
+            #[must_use]
+            pub fn rmatch_indices<P: Pattern<Self>>(&self, pat: P) -> RMatchIndices<'_, Self, P> {
+                RMatchIndices::new(self, pat, self.len())
+            }
+
+            /// Returns an iterator over the lines of this string slice, as string slices.
+            ///
+            /// Lines are split at line endings that are either newlines (`\n`) or sequences of a
+            /// carriage return followed by a line feed (`\r\n`).
+            ///
+            /// Line terminators are not included in the lines returned by the iterator.
+            ///
+            /// The final line ending is optional. A string that ends with a final line ending will
+            /// return the same lines as an otherwise identical string without a final line ending.
+            #[inline]
+            #[must_use]
+            pub fn lines(&self) -> Lines<'_, Self> {
+                Lines::new(self, self.len())
+            }
+
+            /// Splits this string slice by whitespace.
+            ///
+            /// The iterator returned will return string slices that are sub-slices of the original
+            /// string slice, separated by any amount of whitespace.
+            ///
+            /// 'Whitespace' is defined according to the terms of the Unicode Derived Core Property
+            /// `White_Space`. If you only want to split on ASCII whitespace instead, use
+            /// [`split_ascii_whitespace`][Self::split_ascii_whitespace].
+            #[inline]
+            #[must_use]
+            pub fn split_whitespace(&self) -> SplitWhitespace<'_, Self> {
+                SplitWhitespace::new(self, self.len())
+            }
+
+            /// Splits this string slice by ASCII whitespace.
+            ///
+            /// The iterator returned will return string slices that are sub-slices of the original
+            /// string slice, separated by any amount of ASCII whitespace.
+            ///
+            /// To split by Unicode `Whitespace` instead, use
+            /// [`split_whitespace`][Self::split_whitespace].
+            #[inline]
+            #[must_use]
+            pub fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace<'_, Self> {
+                SplitAsciiWhitespace::new(self, self.len())
+            }
+
+            /// Returns an iterator over the grapheme clusters of this string slice.
+            ///
+            /// If `is_extended` is `true`, the iterator is over the extended grapheme clusters;
+            /// otherwise, it is over the legacy grapheme clusters. [UAX#29] recommends extended
+            /// grapheme cluster boundaries for general processing, such as moving a text cursor.
+            ///
+            /// Segmentation is performed by the `unicode-segmentation` crate. The boundaries are
+            /// found lazily as the iterator advances, without allocating.
+            ///
+            /// [UAX#29]: http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries
+            #[inline]
+            #[cfg(feature = "segmentation")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "segmentation")))]
+            #[must_use]
+            pub fn graphemes(&self, is_extended: bool) -> Graphemes<'_, Self> {
+                Graphemes::<Self>::new(self, is_extended)
+            }
+
+            /// Returns an iterator over the grapheme clusters of this string slice and their
+            /// positions.
+            ///
+            /// The position of each grapheme cluster is the offset of its first code unit in
+            /// this string slice. See [`graphemes`][Self::graphemes] for more.
+            #[inline]
+            #[cfg(feature = "segmentation")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "segmentation")))]
+            #[must_use]
+            pub fn grapheme_indices(&self, is_extended: bool) -> GraphemeIndices<'_, Self> {
+                GraphemeIndices::<Self>::new(self, is_extended)
+            }
+
+            /// Returns an iterator over the words of this string slice.
+            ///
+            /// Words are the substrings between the word boundaries defined by [UAX#29] that
+            /// contain at least one alphanumeric character, so whitespace and punctuation are
+            /// not included.
+            ///
+            /// Segmentation is performed by the `unicode-segmentation` crate. The boundaries are
+            /// found lazily as the iterator advances: only the text up to the next line break or
+            /// ASCII space is transcoded at a time, into a buffer that is reused.
+            ///
+            /// [UAX#29]: http://www.unicode.org/reports/tr29/#Word_Boundaries
+            #[inline]
+            #[cfg(feature = "segmentation")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "segmentation")))]
+            #[must_use]
+            pub fn unicode_words(&self) -> UnicodeWords<'_, Self> {
+                UnicodeWords::<Self>::new(self)
+            }
+
+            /// Returns an iterator over the substrings of this string slice separated on the word
+            /// boundaries defined by [UAX#29].
+            ///
+            /// Unlike [`unicode_words`][Self::unicode_words], every part of the string slice is
+            /// included in the iterator, so concatenating the items yields the original string
+            /// slice.
+            ///
+            /// Segmentation is performed by the `unicode-segmentation` crate. The boundaries are
+            /// found lazily as the iterator advances: only the text up to the next line break or
+            /// ASCII space is transcoded at a time, into a buffer that is reused.
+            ///
+            /// [UAX#29]: http://www.unicode.org/reports/tr29/#Word_Boundaries
+            #[inline]
+            #[cfg(feature = "segmentation")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "segmentation")))]
+            #[must_use]
+            pub fn split_word_bounds(&self) -> UWordBounds<'_, Self> {
+                UWordBounds::<Self>::new(self)
+            }
+
+            /// Replaces all matches of a pattern with another string slice.
+            ///
+            /// `replace` creates a new owned string, and copies the data from this string slice
+            /// into it. While doing so, it attempts to find matches of a pattern. If it finds any,
+            /// it replaces them with the replacement string slice.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[inline]
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            #[must_use]
+            pub fn replace<P: Pattern<Self>>(&self, from: P, to: &Self) -> $utfstring {
+                self.replacen(from, to, usize::MAX)
+            }
+
+            /// Replaces the first `count` matches of a pattern with another string slice.
+            ///
+            /// `replacen` creates a new owned string, and copies the data from this string slice
+            /// into it. While doing so, it attempts to find matches of a pattern. If it finds any,
+            /// it replaces them with the replacement string slice at most `count` times.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            #[must_use]
+            pub fn replacen<P: Pattern<Self>>(
+                &self,
+                from: P,
+                to: &Self,
+                count: usize,
+            ) -> $utfstring {
+                let mut result = $utfstring::with_capacity(self.len());
+                let pieces = self.splitn(count.saturating_add(1), from);
+                for (i, piece) in pieces.enumerate() {
+                    if i > 0 {
+                        result.push_utfstr(to);
+                    }
+                    result.push_utfstr(piece);
+                }
+                result
+            }
+
             /// Converts a boxed string into a boxed slice without copying or allocating.
             #[inline]
             #[cfg(feature = "alloc")]
@@ -234,6 +740,115 @@ macro_rules! utfstr_common_impl {
                 unsafe { $utfstring::from_vec_unchecked(self.into_boxed_slice().into_vec()) }
             }
 
+            /// Checks that two strings are an ASCII case-insensitive match.
+            ///
+            /// Same as `to_ascii_lowercase(a) == to_ascii_lowercase(b)`, but without allocating
+            /// and copying temporaries.
+            #[inline]
+            #[must_use]
+            pub fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
+                self.len() == other.len()
+                    && self
+                        .as_slice()
+                        .iter()
+                        .zip(other.as_slice())
+                        .all(|(&a, &b)| ascii_lowercase(a) == ascii_lowercase(b))
+            }
+
+            /// Converts this string to its ASCII upper case equivalent in-place.
+            ///
+            /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', but non-ASCII letters are
+            /// unchanged.
+            ///
+            /// To return a new uppercased value without modifying the existing one, use
+            /// [`to_ascii_uppercase`][Self::to_ascii_uppercase].
+            #[inline]
+            pub fn make_ascii_uppercase(&mut self) {
+                for u in unsafe { self.as_mut_slice() } {
+                    *u = ascii_uppercase(*u);
+                }
+            }
+
+            /// Converts this string to its ASCII lower case equivalent in-place.
+            ///
+            /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', but non-ASCII letters are
+            /// unchanged.
+            ///
+            /// To return a new lowercased value without modifying the existing one, use
+            /// [`to_ascii_lowercase`][Self::to_ascii_lowercase].
+            #[inline]
+            pub fn make_ascii_lowercase(&mut self) {
+                for u in unsafe { self.as_mut_slice() } {
+                    *u = ascii_lowercase(*u);
+                }
+            }
+
+            /// Returns a copy of this string where each character is mapped to its ASCII upper
+            /// case equivalent.
+            ///
+            /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', but non-ASCII letters are
+            /// unchanged.
+            ///
+            /// To uppercase the value in-place, use
+            /// [`make_ascii_uppercase`][Self::make_ascii_uppercase].
+            #[inline]
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            #[must_use]
+            pub fn to_ascii_uppercase(&self) -> $utfstring {
+                let mut s = unsafe { $utfstring::from_vec_unchecked(self.as_slice().to_vec()) };
+                s.make_ascii_uppercase();
+                s
+            }
+
+            /// Returns a copy of this string where each character is mapped to its ASCII lower
+            /// case equivalent.
+            ///
+            /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', but non-ASCII letters are
+            /// unchanged.
+            ///
+            /// To lowercase the value in-place, use
+            /// [`make_ascii_lowercase`][Self::make_ascii_lowercase].
+            #[inline]
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            #[must_use]
+            pub fn to_ascii_lowercase(&self) -> $utfstring {
+                let mut s = unsafe { $utfstring::from_vec_unchecked(self.as_slice().to_vec()) };
+                s.make_ascii_lowercase();
+                s
+            }
+
+            /// Copies the code units of this string to a new vector of bytes, in little-endian
+            /// byte order.
+            #[inline]
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            #[must_use]
+            pub fn to_le_bytes(&self) -> Vec<u8> {
+                units_to_bytes(self.as_slice(), <$uchar>::to_le_bytes)
+            }
+
+            /// Copies the code units of this string to a new vector of bytes, in big-endian
+            /// byte order.
+            #[inline]
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            #[must_use]
+            pub fn to_be_bytes(&self) -> Vec<u8> {
+                units_to_bytes(self.as_slice(), <$uchar>::to_be_bytes)
+            }
+
+            /// Copies the code units of this string to a new vector of bytes, in native-endian
+            /// byte order.
+            #[inline]
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            #[must_use]
+            pub fn to_ne_bytes(&self) -> Vec<u8> {
+                units_to_bytes(self.as_slice(), <$uchar>::to_ne_bytes)
+            }
+
             /// Creates a new owned string by repeating this string `n` times.
             ///
             /// # Panics
@@ -1358,6 +1973,95 @@ impl Utf16Str {
         crate::encode_utf32(self.chars())
     }
 
+    /// Returns the length in bytes of the string encoded as UTF-8.
+    ///
+    /// This computes the exact length without allocating, so it can be used to size a buffer for
+    /// [`encode_utf8_into`][Self::encode_utf8_into] or to reserve capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use widestring::utf16str;
+    ///
+    /// let s = utf16str!("a\u{e9}💖");
+    /// assert_eq!(s.utf8_len(), "a\u{e9}💖".len());
+    /// ```
+    #[must_use]
+    pub fn utf8_len(&self) -> usize {
+        // Each half of a surrogate pair contributes half of the 4-byte UTF-8 sequence
+        self.as_slice()
+            .iter()
+            .map(|&u| match u {
+                0..=0x7F => 1,
+                0x80..=0x7FF | 0xD800..=0xDFFF => 2,
+                _ => 3,
+            })
+            .sum()
+    }
+
+    /// Encodes the string as UTF-8 into `buf`, returning the number of bytes written.
+    ///
+    /// This does not allocate, so it can be used without the `alloc` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buf` is too small, which reports the number of bytes required. The
+    /// contents of `buf` are unspecified in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use widestring::utf16str;
+    ///
+    /// let s = utf16str!("a💖");
+    /// let mut buf = [0; 8];
+    /// let len = s.encode_utf8_into(&mut buf).unwrap();
+    /// assert_eq!(&buf[..len], "a💖".as_bytes());
+    /// assert_eq!(s.encode_utf8_into(&mut buf[..2]).unwrap_err().required(), 5);
+    /// ```
+    pub fn encode_utf8_into(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        encode_chars_into(self.chars(), buf, encode_utf8_unit)
+    }
+
+    /// Encodes the string as UTF-32 into `buf`, returning the number of code units
+    /// written.
+    ///
+    /// This does not allocate, so it can be used without the `alloc` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buf` is too small, which reports the number of code units required.
+    /// The contents of `buf` are unspecified in that case.
+    pub fn encode_utf32_into(&self, buf: &mut [u32]) -> Result<usize, BufferTooSmall> {
+        encode_chars_into(self.chars(), buf, encode_utf32_unit)
+    }
+
+    /// Encodes a string slice as UTF-16 into `buf`, returning the encoded part of `buf` as a
+    /// string slice.
+    ///
+    /// This does not allocate, so it can be used without the `alloc` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buf` is too small, which reports the number of code units required.
+    /// The contents of `buf` are unspecified in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use widestring::Utf16Str;
+    ///
+    /// let mut buf = [0; 4];
+    /// let s = Utf16Str::from_str_into("a💖", &mut buf).unwrap();
+    /// assert_eq!(s.as_slice(), &[0x61, 0xD83D, 0xDC96]);
+    /// assert_eq!(Utf16Str::from_str_into("abcde", &mut buf).unwrap_err().required(), 5);
+    /// ```
+    pub fn from_str_into<'a>(s: &str, buf: &'a mut [u16]) -> Result<&'a mut Self, BufferTooSmall> {
+        let len = encode_chars_into(s.chars(), buf, encode_utf16_unit)?;
+        // SAFETY: Encoded from valid chars
+        Ok(unsafe { Self::from_slice_unchecked_mut(&mut buf[..len]) })
+    }
+
     /// Returns an iterator that escapes each [`char`] in `self` with [`char::escape_debug`].
     #[inline]
     #[must_use]
@@ -1418,7 +2122,264 @@ impl Utf16Str {
                 s.push(lower);
             }
         }
-        s
+        s
+    }
+
+    /// Compares two strings in Unicode code point order.
+    ///
+    /// The [`Ord`] implementation of [`Utf16Str`] compares code units, which sorts supplementary
+    /// characters (encoded as surrogate pairs) before the characters `U+E000` to `U+FFFF`. This
+    /// method instead sorts strings in the same order as the equivalent [`str`] or [`Utf32Str`]
+    /// values.
+    ///
+    /// To use this ordering in sorted collections, wrap the strings in
+    /// [`CodePointOrd`][crate::ustr::CodePointOrd].
+    #[inline]
+    #[must_use]
+    pub fn cmp_code_point(&self, other: &Utf16Str) -> core::cmp::Ordering {
+        cmp_utf16_code_point(self.as_slice(), other.as_slice())
+    }
+
+    /// Returns an iterator over the full Unicode case folding of the [`char`]s of this string
+    /// slice.
+    ///
+    /// Case folding is used for caseless matching of strings, and is more appropriate for that
+    /// purpose than [`to_lowercase`][Self::to_lowercase] or
+    /// [`to_uppercase`][Self::to_uppercase]. Full case folding may map a single [`char`] to
+    /// multiple [`char`]s, such as `ß` to `ss`. Case folding is based on `CaseFolding.txt` of
+    /// Unicode 14.0.0, and does not use the Turkic (`T`) mappings.
+    #[inline]
+    #[must_use]
+    pub fn casefold(&self) -> CaseFold<CharsUtf16<'_>> {
+        CaseFold::new(self.chars())
+    }
+
+    /// Returns an iterator over the simple Unicode case folding of the [`char`]s of this string
+    /// slice.
+    ///
+    /// Unlike [`casefold`][Self::casefold], simple case folding always maps each [`char`] to a
+    /// single [`char`], so that the folded string has a predictable length but does not match
+    /// all caseless equivalents.
+    #[inline]
+    #[must_use]
+    pub fn casefold_simple(&self) -> CaseFoldSimple<CharsUtf16<'_>> {
+        CaseFoldSimple::new(self.chars())
+    }
+
+    /// Returns the full Unicode case folding of this string slice, as a new [`Utf16String`].
+    ///
+    /// See [`casefold`][Self::casefold] for more details.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn to_casefold(&self) -> Utf16String {
+        let mut s = Utf16String::with_capacity(self.len());
+        for c in self.casefold() {
+            s.push(c);
+        }
+        s
+    }
+
+    /// Returns the simple Unicode case folding of this string slice, as a new [`Utf16String`].
+    ///
+    /// See [`casefold_simple`][Self::casefold_simple] for more details.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn to_casefold_simple(&self) -> Utf16String {
+        let mut s = Utf16String::with_capacity(self.len());
+        for c in self.casefold_simple() {
+            s.push(c);
+        }
+        s
+    }
+
+    /// Checks that two strings are a caseless match, using full Unicode case folding.
+    ///
+    /// Same as `a.to_casefold() == b.to_casefold()`, but without allocating and copying
+    /// temporaries. See [`casefold`][Self::casefold] for more details.
+    #[inline]
+    #[must_use]
+    pub fn eq_ignore_case(&self, other: &Utf16Str) -> bool {
+        self.casefold().eq(other.casefold())
+    }
+
+    /// Returns an iterator over the [`char`]s of this string slice in Unicode Normalization Form
+    /// C (canonical composition).
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn nfc(&self) -> Nfc<CharsUtf16<'_>> {
+        Nfc::new(unicode_normalization::UnicodeNormalization::nfc(
+            self.chars(),
+        ))
+    }
+
+    /// Returns an iterator over the [`char`]s of this string slice in Unicode Normalization Form
+    /// D (canonical decomposition).
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn nfd(&self) -> Nfd<CharsUtf16<'_>> {
+        Nfd::new(unicode_normalization::UnicodeNormalization::nfd(
+            self.chars(),
+        ))
+    }
+
+    /// Returns an iterator over the [`char`]s of this string slice in Unicode Normalization Form
+    /// KC (compatibility composition).
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn nfkc(&self) -> Nfkc<CharsUtf16<'_>> {
+        Nfkc::new(unicode_normalization::UnicodeNormalization::nfkc(
+            self.chars(),
+        ))
+    }
+
+    /// Returns an iterator over the [`char`]s of this string slice in Unicode Normalization Form
+    /// KD (compatibility decomposition).
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn nfkd(&self) -> Nfkd<CharsUtf16<'_>> {
+        Nfkd::new(unicode_normalization::UnicodeNormalization::nfkd(
+            self.chars(),
+        ))
+    }
+
+    /// Returns this string slice in Unicode Normalization Form C (canonical composition), as a new
+    /// [`Utf16String`].
+    ///
+    /// See [`nfc`][Self::nfc] for a lazy iterator.
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn to_nfc(&self) -> Utf16String {
+        let mut s = Utf16String::with_capacity(self.len());
+        for c in self.nfc() {
+            s.push(c);
+        }
+        s
+    }
+
+    /// Returns this string slice in Unicode Normalization Form D (canonical decomposition), as a
+    /// new [`Utf16String`].
+    ///
+    /// See [`nfd`][Self::nfd] for a lazy iterator.
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn to_nfd(&self) -> Utf16String {
+        let mut s = Utf16String::with_capacity(self.len());
+        for c in self.nfd() {
+            s.push(c);
+        }
+        s
+    }
+
+    /// Returns this string slice in Unicode Normalization Form KC (compatibility composition), as a
+    /// new [`Utf16String`].
+    ///
+    /// See [`nfkc`][Self::nfkc] for a lazy iterator.
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn to_nfkc(&self) -> Utf16String {
+        let mut s = Utf16String::with_capacity(self.len());
+        for c in self.nfkc() {
+            s.push(c);
+        }
+        s
+    }
+
+    /// Returns this string slice in Unicode Normalization Form KD (compatibility decomposition), as
+    /// a new [`Utf16String`].
+    ///
+    /// See [`nfkd`][Self::nfkd] for a lazy iterator.
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn to_nfkd(&self) -> Utf16String {
+        let mut s = Utf16String::with_capacity(self.len());
+        for c in self.nfkd() {
+            s.push(c);
+        }
+        s
+    }
+
+    /// Returns whether this string slice is in Unicode Normalization Form C (canonical
+    /// composition).
+    ///
+    /// This uses the quick check algorithm, and only falls back to normalizing the string if the
+    /// quick check is inconclusive.
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn is_nfc(&self) -> bool {
+        match unicode_normalization::is_nfc_quick(self.chars()) {
+            unicode_normalization::IsNormalized::Yes => true,
+            unicode_normalization::IsNormalized::No => false,
+            unicode_normalization::IsNormalized::Maybe => self.chars().eq(self.nfc()),
+        }
+    }
+
+    /// Returns whether this string slice is in Unicode Normalization Form D (canonical
+    /// decomposition).
+    ///
+    /// This uses the quick check algorithm, and only falls back to normalizing the string if the
+    /// quick check is inconclusive.
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn is_nfd(&self) -> bool {
+        match unicode_normalization::is_nfd_quick(self.chars()) {
+            unicode_normalization::IsNormalized::Yes => true,
+            unicode_normalization::IsNormalized::No => false,
+            unicode_normalization::IsNormalized::Maybe => self.chars().eq(self.nfd()),
+        }
+    }
+
+    /// Returns whether this string slice is in Unicode Normalization Form KC (compatibility
+    /// composition).
+    ///
+    /// This uses the quick check algorithm, and only falls back to normalizing the string if the
+    /// quick check is inconclusive.
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn is_nfkc(&self) -> bool {
+        match unicode_normalization::is_nfkc_quick(self.chars()) {
+            unicode_normalization::IsNormalized::Yes => true,
+            unicode_normalization::IsNormalized::No => false,
+            unicode_normalization::IsNormalized::Maybe => self.chars().eq(self.nfkc()),
+        }
+    }
+
+    /// Returns whether this string slice is in Unicode Normalization Form KD (compatibility
+    /// decomposition).
+    ///
+    /// This uses the quick check algorithm, and only falls back to normalizing the string if the
+    /// quick check is inconclusive.
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn is_nfkd(&self) -> bool {
+        match unicode_normalization::is_nfkd_quick(self.chars()) {
+            unicode_normalization::IsNormalized::Yes => true,
+            unicode_normalization::IsNormalized::No => false,
+            unicode_normalization::IsNormalized::Maybe => self.chars().eq(self.nfkd()),
+        }
     }
 }
 
@@ -1963,6 +2924,105 @@ impl Utf32Str {
         crate::encode_utf16(self.chars())
     }
 
+    /// Returns the length in bytes of the string encoded as UTF-8.
+    ///
+    /// This computes the exact length without allocating, so it can be used to size a buffer for
+    /// [`encode_utf8_into`][Self::encode_utf8_into] or to reserve capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use widestring::utf32str;
+    ///
+    /// let s = utf32str!("a\u{e9}💖");
+    /// assert_eq!(s.utf8_len(), "a\u{e9}💖".len());
+    /// ```
+    #[must_use]
+    pub fn utf8_len(&self) -> usize {
+        self.chars().map(char::len_utf8).sum()
+    }
+
+    /// Returns the length in code units of the string encoded as UTF-16.
+    ///
+    /// This computes the exact length without allocating, so it can be used to size a buffer for
+    /// [`encode_utf16_into`][Self::encode_utf16_into] or to reserve capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use widestring::utf32str;
+    ///
+    /// let s = utf32str!("a\u{e9}💖");
+    /// assert_eq!(s.utf16_len(), 4);
+    /// ```
+    #[must_use]
+    pub fn utf16_len(&self) -> usize {
+        self.chars().map(char::len_utf16).sum()
+    }
+
+    /// Encodes the string as UTF-8 into `buf`, returning the number of bytes written.
+    ///
+    /// This does not allocate, so it can be used without the `alloc` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buf` is too small, which reports the number of bytes required. The
+    /// contents of `buf` are unspecified in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use widestring::utf32str;
+    ///
+    /// let s = utf32str!("a💖");
+    /// let mut buf = [0; 8];
+    /// let len = s.encode_utf8_into(&mut buf).unwrap();
+    /// assert_eq!(&buf[..len], "a💖".as_bytes());
+    /// assert_eq!(s.encode_utf8_into(&mut buf[..2]).unwrap_err().required(), 5);
+    /// ```
+    pub fn encode_utf8_into(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        encode_chars_into(self.chars(), buf, encode_utf8_unit)
+    }
+
+    /// Encodes the string as UTF-16 into `buf`, returning the number of code units
+    /// written.
+    ///
+    /// This does not allocate, so it can be used without the `alloc` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buf` is too small, which reports the number of code units required.
+    /// The contents of `buf` are unspecified in that case.
+    pub fn encode_utf16_into(&self, buf: &mut [u16]) -> Result<usize, BufferTooSmall> {
+        encode_chars_into(self.chars(), buf, encode_utf16_unit)
+    }
+
+    /// Encodes a string slice as UTF-32 into `buf`, returning the encoded part of `buf` as a
+    /// string slice.
+    ///
+    /// This does not allocate, so it can be used without the `alloc` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buf` is too small, which reports the number of code units required.
+    /// The contents of `buf` are unspecified in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use widestring::Utf32Str;
+    ///
+    /// let mut buf = [0; 4];
+    /// let s = Utf32Str::from_str_into("a💖", &mut buf).unwrap();
+    /// assert_eq!(s.as_slice(), &[0x61, 0x1F496]);
+    /// assert_eq!(Utf32Str::from_str_into("abcde", &mut buf).unwrap_err().required(), 5);
+    /// ```
+    pub fn from_str_into<'a>(s: &str, buf: &'a mut [u32]) -> Result<&'a mut Self, BufferTooSmall> {
+        let len = encode_chars_into(s.chars(), buf, encode_utf32_unit)?;
+        // SAFETY: Encoded from valid chars
+        Ok(unsafe { Self::from_slice_unchecked_mut(&mut buf[..len]) })
+    }
+
     /// Returns an iterator that escapes each [`char`] in `self` with [`char::escape_debug`].
     #[inline]
     #[must_use]
@@ -2025,6 +3085,248 @@ impl Utf32Str {
         }
         s
     }
+
+    /// Returns an iterator over the full Unicode case folding of the [`char`]s of this string
+    /// slice.
+    ///
+    /// Case folding is used for caseless matching of strings, and is more appropriate for that
+    /// purpose than [`to_lowercase`][Self::to_lowercase] or
+    /// [`to_uppercase`][Self::to_uppercase]. Full case folding may map a single [`char`] to
+    /// multiple [`char`]s, such as `ß` to `ss`. Case folding is based on `CaseFolding.txt` of
+    /// Unicode 14.0.0, and does not use the Turkic (`T`) mappings.
+    #[inline]
+    #[must_use]
+    pub fn casefold(&self) -> CaseFold<CharsUtf32<'_>> {
+        CaseFold::new(self.chars())
+    }
+
+    /// Returns an iterator over the simple Unicode case folding of the [`char`]s of this string
+    /// slice.
+    ///
+    /// Unlike [`casefold`][Self::casefold], simple case folding always maps each [`char`] to a
+    /// single [`char`], so that the folded string has a predictable length but does not match
+    /// all caseless equivalents.
+    #[inline]
+    #[must_use]
+    pub fn casefold_simple(&self) -> CaseFoldSimple<CharsUtf32<'_>> {
+        CaseFoldSimple::new(self.chars())
+    }
+
+    /// Returns the full Unicode case folding of this string slice, as a new [`Utf32String`].
+    ///
+    /// See [`casefold`][Self::casefold] for more details.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn to_casefold(&self) -> Utf32String {
+        let mut s = Utf32String::with_capacity(self.len());
+        for c in self.casefold() {
+            s.push(c);
+        }
+        s
+    }
+
+    /// Returns the simple Unicode case folding of this string slice, as a new [`Utf32String`].
+    ///
+    /// See [`casefold_simple`][Self::casefold_simple] for more details.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn to_casefold_simple(&self) -> Utf32String {
+        let mut s = Utf32String::with_capacity(self.len());
+        for c in self.casefold_simple() {
+            s.push(c);
+        }
+        s
+    }
+
+    /// Checks that two strings are a caseless match, using full Unicode case folding.
+    ///
+    /// Same as `a.to_casefold() == b.to_casefold()`, but without allocating and copying
+    /// temporaries. See [`casefold`][Self::casefold] for more details.
+    #[inline]
+    #[must_use]
+    pub fn eq_ignore_case(&self, other: &Utf32Str) -> bool {
+        self.casefold().eq(other.casefold())
+    }
+
+    /// Returns an iterator over the [`char`]s of this string slice in Unicode Normalization Form
+    /// C (canonical composition).
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn nfc(&self) -> Nfc<CharsUtf32<'_>> {
+        Nfc::new(unicode_normalization::UnicodeNormalization::nfc(
+            self.chars(),
+        ))
+    }
+
+    /// Returns an iterator over the [`char`]s of this string slice in Unicode Normalization Form
+    /// D (canonical decomposition).
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn nfd(&self) -> Nfd<CharsUtf32<'_>> {
+        Nfd::new(unicode_normalization::UnicodeNormalization::nfd(
+            self.chars(),
+        ))
+    }
+
+    /// Returns an iterator over the [`char`]s of this string slice in Unicode Normalization Form
+    /// KC (compatibility composition).
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn nfkc(&self) -> Nfkc<CharsUtf32<'_>> {
+        Nfkc::new(unicode_normalization::UnicodeNormalization::nfkc(
+            self.chars(),
+        ))
+    }
+
+    /// Returns an iterator over the [`char`]s of this string slice in Unicode Normalization Form
+    /// KD (compatibility decomposition).
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn nfkd(&self) -> Nfkd<CharsUtf32<'_>> {
+        Nfkd::new(unicode_normalization::UnicodeNormalization::nfkd(
+            self.chars(),
+        ))
+    }
+
+    /// Returns this string slice in Unicode Normalization Form C (canonical composition), as a new
+    /// [`Utf32String`].
+    ///
+    /// See [`nfc`][Self::nfc] for a lazy iterator.
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn to_nfc(&self) -> Utf32String {
+        let mut s = Utf32String::with_capacity(self.len());
+        for c in self.nfc() {
+            s.push(c);
+        }
+        s
+    }
+
+    /// Returns this string slice in Unicode Normalization Form D (canonical decomposition), as a
+    /// new [`Utf32String`].
+    ///
+    /// See [`nfd`][Self::nfd] for a lazy iterator.
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn to_nfd(&self) -> Utf32String {
+        let mut s = Utf32String::with_capacity(self.len());
+        for c in self.nfd() {
+            s.push(c);
+        }
+        s
+    }
+
+    /// Returns this string slice in Unicode Normalization Form KC (compatibility composition), as a
+    /// new [`Utf32String`].
+    ///
+    /// See [`nfkc`][Self::nfkc] for a lazy iterator.
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn to_nfkc(&self) -> Utf32String {
+        let mut s = Utf32String::with_capacity(self.len());
+        for c in self.nfkc() {
+            s.push(c);
+        }
+        s
+    }
+
+    /// Returns this string slice in Unicode Normalization Form KD (compatibility decomposition), as
+    /// a new [`Utf32String`].
+    ///
+    /// See [`nfkd`][Self::nfkd] for a lazy iterator.
+    #[inline]
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn to_nfkd(&self) -> Utf32String {
+        let mut s = Utf32String::with_capacity(self.len());
+        for c in self.nfkd() {
+            s.push(c);
+        }
+        s
+    }
+
+    /// Returns whether this string slice is in Unicode Normalization Form C (canonical
+    /// composition).
+    ///
+    /// This uses the quick check algorithm, and only falls back to normalizing the string if the
+    /// quick check is inconclusive.
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn is_nfc(&self) -> bool {
+        match unicode_normalization::is_nfc_quick(self.chars()) {
+            unicode_normalization::IsNormalized::Yes => true,
+            unicode_normalization::IsNormalized::No => false,
+            unicode_normalization::IsNormalized::Maybe => self.chars().eq(self.nfc()),
+        }
+    }
+
+    /// Returns whether this string slice is in Unicode Normalization Form D (canonical
+    /// decomposition).
+    ///
+    /// This uses the quick check algorithm, and only falls back to normalizing the string if the
+    /// quick check is inconclusive.
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn is_nfd(&self) -> bool {
+        match unicode_normalization::is_nfd_quick(self.chars()) {
+            unicode_normalization::IsNormalized::Yes => true,
+            unicode_normalization::IsNormalized::No => false,
+            unicode_normalization::IsNormalized::Maybe => self.chars().eq(self.nfd()),
+        }
+    }
+
+    /// Returns whether this string slice is in Unicode Normalization Form KC (compatibility
+    /// composition).
+    ///
+    /// This uses the quick check algorithm, and only falls back to normalizing the string if the
+    /// quick check is inconclusive.
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn is_nfkc(&self) -> bool {
+        match unicode_normalization::is_nfkc_quick(self.chars()) {
+            unicode_normalization::IsNormalized::Yes => true,
+            unicode_normalization::IsNormalized::No => false,
+            unicode_normalization::IsNormalized::Maybe => self.chars().eq(self.nfkc()),
+        }
+    }
+
+    /// Returns whether this string slice is in Unicode Normalization Form KD (compatibility
+    /// decomposition).
+    ///
+    /// This uses the quick check algorithm, and only falls back to normalizing the string if the
+    /// quick check is inconclusive.
+    #[cfg(feature = "normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "normalization")))]
+    #[must_use]
+    pub fn is_nfkd(&self) -> bool {
+        match unicode_normalization::is_nfkd_quick(self.chars()) {
+            unicode_normalization::IsNormalized::Yes => true,
+            unicode_normalization::IsNormalized::No => false,
+            unicode_normalization::IsNormalized::Maybe => self.chars().eq(self.nfkd()),
+        }
+    }
 }
 
 impl AsMut<[char]> for Utf32Str {
@@ -2244,4 +3546,622 @@ mod test {
         let s = utf32str!("  עברית  ");
         assert!(Some('ע') == s.trim_start().chars().next());
     }
+
+    #[test]
+    fn utf16_find() {
+        let s = utf16str!("a💖b💖c");
+        assert_eq!(s.find('💖'), Some(1));
+        assert_eq!(s.rfind('💖'), Some(4));
+        assert_eq!(s.find("b💖"), Some(3));
+        assert_eq!(s.rfind(utf16str!("💖")), Some(4));
+        assert_eq!(s.find(&['c', 'b'][..]), Some(3));
+        assert_eq!(s.rfind(|c: char| c.is_ascii()), Some(6));
+        assert_eq!(s.find(""), Some(0));
+        assert_eq!(s.rfind(""), Some(s.len()));
+        assert_eq!(s.find('d'), None);
+
+        assert!(s.contains("💖c"));
+        assert!(!s.contains("💖💖"));
+        assert!(s.starts_with("a💖"));
+        assert!(s.starts_with(['a', 'b']));
+        assert!(!s.starts_with('💖'));
+        assert!(s.ends_with(utf16str!("💖c")));
+        assert!(s.ends_with(char::is_alphabetic));
+        assert!(!s.ends_with("b"));
+    }
+
+    #[test]
+    fn utf32_find() {
+        let s = utf32str!("a💖b💖c");
+        assert_eq!(s.find('💖'), Some(1));
+        assert_eq!(s.rfind('💖'), Some(3));
+        assert_eq!(s.find("b💖"), Some(2));
+        assert_eq!(s.rfind(utf32str!("💖")), Some(3));
+        assert_eq!(s.find(&['c', 'b'][..]), Some(2));
+        assert_eq!(s.rfind(|c: char| c.is_ascii()), Some(4));
+        assert_eq!(s.find(""), Some(0));
+        assert_eq!(s.rfind(""), Some(s.len()));
+        assert_eq!(s.find('d'), None);
+
+        assert!(s.contains("💖c"));
+        assert!(!s.contains("💖💖"));
+        assert!(s.starts_with("a💖"));
+        assert!(s.ends_with(utf32str!("💖c")));
+        assert!(!s.ends_with("b"));
+    }
+
+    #[test]
+    fn find_long_needle() {
+        // Longer than the needle buffer, with near misses sharing the first code unit
+        let needle = "💖abcdefghijklmnopqrstuvwxyz0123456789";
+        let s = utf16str!(
+            "💖abcdefghijklmnopqrstuvwxyz012345678💖abcdefghijklmnopqrstuvwxyz0123456789💖abc"
+        );
+        assert_eq!(s.find(needle), Some(37));
+        assert_eq!(s.rfind(needle), Some(37));
+        assert!(!s.contains("💖abcdefghijklmnopqrstuvwxyz0123456789💖abcd"));
+        let s = utf32str!(
+            "💖abcdefghijklmnopqrstuvwxyz012345678💖abcdefghijklmnopqrstuvwxyz0123456789💖abc"
+        );
+        assert_eq!(s.find(needle), Some(36));
+        assert_eq!(s.rfind(needle), Some(36));
+        assert!(!s.contains("💖abcdefghijklmnopqrstuvwxyz0123456789💖abcd"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf16_split() {
+        use alloc::vec::Vec;
+
+        let s = utf16str!("PATH=C:\\Windows;;C:\\💖;");
+        let (key, value) = s.split_once('=').unwrap();
+        assert_eq!(key, "PATH");
+        assert_eq!(
+            value.split(';').collect::<Vec<_>>(),
+            ["C:\\Windows", "", "C:\\💖", ""]
+        );
+        assert_eq!(
+            value.rsplit(';').collect::<Vec<_>>(),
+            ["", "C:\\💖", "", "C:\\Windows"]
+        );
+        assert_eq!(
+            value.split_terminator(';').collect::<Vec<_>>(),
+            ["C:\\Windows", "", "C:\\💖"]
+        );
+        assert_eq!(
+            value.split_inclusive(';').collect::<Vec<_>>(),
+            ["C:\\Windows;", ";", "C:\\💖;"]
+        );
+        assert_eq!(
+            value.splitn(2, ';').collect::<Vec<_>>(),
+            ["C:\\Windows", ";C:\\💖;"]
+        );
+        assert_eq!(
+            value.rsplitn(2, "\\").collect::<Vec<_>>(),
+            ["💖;", "C:\\Windows;;C:"]
+        );
+        assert_eq!(value.splitn(0, ';').next(), None);
+        assert_eq!(
+            value.rsplit_once(char::is_alphabetic),
+            Some((utf16str!("C:\\Windows;;"), utf16str!(":\\💖;")))
+        );
+        assert_eq!(s.split_once('x'), None);
+
+        assert_eq!(
+            utf16str!("a💖b").split("").collect::<Vec<_>>(),
+            ["", "a", "💖", "b", ""]
+        );
+        assert_eq!(
+            utf16str!("a💖b").rsplit("").collect::<Vec<_>>(),
+            ["", "b", "💖", "a", ""]
+        );
+        assert_eq!(utf16str!("").split(',').collect::<Vec<_>>(), [""]);
+        assert_eq!(utf16str!("").split_terminator(',').count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf32_split() {
+        use alloc::vec::Vec;
+
+        let s = utf32str!("a,b💖c,💖");
+        assert_eq!(s.split(',').collect::<Vec<_>>(), ["a", "b💖c", "💖"]);
+        assert_eq!(s.rsplit("💖").collect::<Vec<_>>(), ["", "c,", "a,b"]);
+        assert_eq!(
+            s.splitn(2, ['💖', ',']).collect::<Vec<_>>(),
+            ["a", "b💖c,💖"]
+        );
+        assert_eq!(s.rsplitn(2, ',').collect::<Vec<_>>(), ["💖", "a,b💖c"]);
+        assert_eq!(s.split_terminator('💖').collect::<Vec<_>>(), ["a,b", "c,"]);
+        assert_eq!(
+            s.split_inclusive(',').collect::<Vec<_>>(),
+            ["a,", "b💖c,", "💖"]
+        );
+        assert_eq!(
+            s.split_once("💖"),
+            Some((utf32str!("a,b"), utf32str!("c,💖")))
+        );
+        assert_eq!(
+            s.rsplit_once("💖"),
+            Some((utf32str!("a,b💖c,"), utf32str!("")))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf16_replace() {
+        use alloc::borrow::ToOwned;
+
+        let s = utf16str!("HKLM\\Software\\💖\\Software");
+        assert_eq!(
+            s.replace("Software", utf16str!("SOFTWARE")),
+            "HKLM\\SOFTWARE\\💖\\SOFTWARE"
+        );
+        assert_eq!(
+            s.replacen('\\', utf16str!("/"), 2),
+            "HKLM/Software/💖\\Software"
+        );
+        assert_eq!(s.replace('x', utf16str!("y")), s);
+        assert_eq!(s.replacen('\\', utf16str!("/"), 0), s);
+        assert_eq!(utf16str!("a💖").replace("", utf16str!("-")), "-a-💖-");
+
+        let mut owned = s.to_owned();
+        owned.replace_all(utf16str!("\\"), utf16str!("\\\\"));
+        assert_eq!(owned, "HKLM\\\\Software\\\\💖\\\\Software");
+        owned.replace_all(char::is_uppercase, utf16str!(""));
+        assert_eq!(owned, "\\\\oftware\\\\💖\\\\oftware");
+
+        let mut owned = utf16str!("a💖").to_owned();
+        owned.replace_all("", utf16str!("-"));
+        assert_eq!(owned, "-a-💖-");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf32_replace() {
+        use alloc::borrow::ToOwned;
+
+        let s = utf32str!("a💖b💖c");
+        assert_eq!(s.replace('💖', utf32str!("--")), "a--b--c");
+        assert_eq!(s.replacen("💖", utf32str!(""), 1), "ab💖c");
+        assert_eq!(s.replace("", utf32str!(".")), ".a.💖.b.💖.c.");
+
+        let mut owned = s.to_owned();
+        owned.replace_all(['a', 'c'], utf32str!("💖"));
+        assert_eq!(owned, "💖💖b💖💖");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf16_lines() {
+        use alloc::vec::Vec;
+
+        let s = utf16str!("foo\r\nbar\n\nbaz💖\r\n");
+        assert_eq!(s.lines().collect::<Vec<_>>(), ["foo", "bar", "", "baz💖"]);
+
+        let s = utf16str!("foo\nbar\n\r\nbaz\r");
+        assert_eq!(s.lines().collect::<Vec<_>>(), ["foo", "bar", "", "baz\r"]);
+
+        assert_eq!(utf16str!("").lines().count(), 0);
+        assert_eq!(utf16str!("\n").lines().collect::<Vec<_>>(), [""]);
+
+        let s = utf16str!(" Mary   had\ta\u{2009}little  \n\t lamb 💖");
+        assert_eq!(
+            s.split_whitespace().collect::<Vec<_>>(),
+            ["Mary", "had", "a", "little", "lamb", "💖"]
+        );
+        assert_eq!(
+            s.split_ascii_whitespace().collect::<Vec<_>>(),
+            ["Mary", "had", "a\u{2009}little", "lamb", "💖"]
+        );
+        assert_eq!(utf16str!("   ").split_whitespace().count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf32_lines() {
+        use alloc::vec::Vec;
+
+        let s = utf32str!("foo\r\nbar\n\nbaz💖\r\n");
+        assert_eq!(s.lines().collect::<Vec<_>>(), ["foo", "bar", "", "baz💖"]);
+
+        let s = utf32str!(" Mary   had\ta\u{2009}little  \n\t lamb 💖");
+        assert_eq!(
+            s.split_whitespace().collect::<Vec<_>>(),
+            ["Mary", "had", "a", "little", "lamb", "💖"]
+        );
+        assert_eq!(
+            s.split_ascii_whitespace().collect::<Vec<_>>(),
+            ["Mary", "had", "a\u{2009}little", "lamb", "💖"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf16_matches() {
+        use alloc::vec::Vec;
+
+        let s = utf16str!("💖abc💖abc💖");
+        assert_eq!(
+            s.match_indices("💖").collect::<Vec<_>>(),
+            [
+                (0, utf16str!("💖")),
+                (5, utf16str!("💖")),
+                (10, utf16str!("💖"))
+            ]
+        );
+        assert_eq!(
+            s.rmatch_indices(char::is_alphabetic)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>(),
+            [9, 8, 7, 4, 3, 2]
+        );
+        assert_eq!(s.matches("abc").count(), 2);
+        assert_eq!(
+            s.rmatches(['c', '💖']).collect::<Vec<_>>(),
+            ["💖", "c", "💖", "c", "💖"]
+        );
+
+        let s = utf16str!("aaa");
+        assert_eq!(
+            s.match_indices("aa").collect::<Vec<_>>(),
+            [(0, utf16str!("aa"))]
+        );
+        assert_eq!(
+            s.rmatch_indices("aa").collect::<Vec<_>>(),
+            [(1, utf16str!("aa"))]
+        );
+        assert_eq!(
+            utf16str!("a💖")
+                .match_indices("")
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>(),
+            [0, 1, 3]
+        );
+        assert_eq!(
+            utf16str!("a💖")
+                .rmatch_indices("")
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>(),
+            [3, 1, 0]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf32_matches() {
+        use alloc::vec::Vec;
+
+        let s = utf32str!("💖abc💖abc💖");
+        assert_eq!(
+            s.match_indices('💖').map(|(i, _)| i).collect::<Vec<_>>(),
+            [0, 4, 8]
+        );
+        assert_eq!(
+            s.rmatch_indices("bc").map(|(i, _)| i).collect::<Vec<_>>(),
+            [6, 2]
+        );
+        assert_eq!(s.matches(char::is_alphabetic).count(), 6);
+        assert_eq!(s.rmatches("c💖").collect::<Vec<_>>(), ["c💖", "c💖"]);
+    }
+
+    #[test]
+    fn utf16_strip() {
+        let s = utf16str!("\\\\?\\C:\\Users\\💖\\\\");
+        assert_eq!(
+            s.strip_prefix("\\\\?\\"),
+            Some(utf16str!("C:\\Users\\💖\\\\"))
+        );
+        assert_eq!(s.strip_prefix("C:"), None);
+        assert_eq!(
+            s.strip_suffix('\\'),
+            Some(utf16str!("\\\\?\\C:\\Users\\💖\\"))
+        );
+        assert_eq!(s.strip_suffix(""), Some(s));
+        assert_eq!(s.trim_end_matches('\\'), utf16str!("\\\\?\\C:\\Users\\💖"));
+        assert_eq!(
+            s.trim_start_matches(['\\', '?']),
+            utf16str!("C:\\Users\\💖\\\\")
+        );
+        assert_eq!(
+            s.trim_matches(|c: char| !c.is_alphabetic()),
+            utf16str!("C:\\Users")
+        );
+        assert_eq!(utf16str!("💖💖x💖").trim_matches("💖"), utf16str!("x"));
+        assert_eq!(utf16str!("xyx").trim_matches(""), utf16str!("xyx"));
+    }
+
+    #[test]
+    fn utf32_strip() {
+        let s = utf32str!("💖💖x💖");
+        assert_eq!(s.strip_prefix('💖'), Some(utf32str!("💖x💖")));
+        assert_eq!(s.strip_suffix("x💖"), Some(utf32str!("💖💖")));
+        assert_eq!(s.strip_suffix('x'), None);
+        assert_eq!(s.trim_start_matches('💖'), utf32str!("x💖"));
+        assert_eq!(s.trim_end_matches(utf32str!("💖")), utf32str!("💖💖x"));
+        assert_eq!(s.trim_matches(&['💖'][..]), utf32str!("x"));
+    }
+
+    #[test]
+    fn utf16_ascii_case() {
+        let s = utf16str!("Grüße.TXT");
+        assert!(s.eq_ignore_ascii_case(utf16str!("grüße.txt")));
+        assert!(!s.eq_ignore_ascii_case(utf16str!("GRÜSSE.TXT")));
+
+        let mut buf = [0; 9];
+        buf.copy_from_slice(s.as_slice());
+        let s = Utf16Str::from_slice_mut(&mut buf).unwrap();
+        s.make_ascii_lowercase();
+        assert_eq!(s, utf16str!("grüße.txt"));
+        s.make_ascii_uppercase();
+        assert_eq!(s, utf16str!("GRüßE.TXT"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf32_ascii_case() {
+        let s = utf32str!("Ünïcode 💖 Ascii");
+        assert_eq!(s.to_ascii_uppercase(), utf32str!("ÜNïCODE 💖 ASCII"));
+        assert_eq!(s.to_ascii_lowercase(), utf32str!("Ünïcode 💖 ascii"));
+        assert!(!s.eq_ignore_ascii_case(utf32str!("üNïCODE 💖 aSCII")));
+        assert!(s.eq_ignore_ascii_case(utf32str!("ÜNïCODE 💖 ASCII")));
+    }
+
+    #[test]
+    fn utf16_casefold() {
+        let s = utf16str!("Straße ΣΑΣ 𐐀");
+        assert!(s.casefold().eq("strasse σασ 𐐨".chars()));
+        assert!(s.casefold_simple().eq("straße σασ 𐐨".chars()));
+        assert!(s.eq_ignore_case(utf16str!("STRASSE σας 𐐨")));
+        assert!(s.eq_ignore_case(utf16str!("STRASSE σασ 𐐨")));
+        assert!(utf16str!("ẞ").eq_ignore_case(utf16str!("ss")));
+        assert!(!utf16str!("ẞ").eq_ignore_case(utf16str!("s")));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf32_casefold() {
+        let s = utf32str!("ﬃ ᾈ ΐ \u{212A}elvin");
+        assert_eq!(
+            s.to_casefold(),
+            utf32str!("ffi ἀι \u{3B9}\u{308}\u{301} kelvin")
+        );
+        assert_eq!(s.to_casefold_simple(), utf32str!("ﬃ ᾀ ΐ kelvin"));
+        assert!(s.eq_ignore_case(utf32str!("FFI ἈΙ \u{3B9}\u{308}\u{301} KELVIN")));
+    }
+
+    #[test]
+    #[cfg(feature = "normalization")]
+    fn utf16_normalization() {
+        let nfc = utf16str!("Am\u{e9}lie");
+        let nfd = utf16str!("Ame\u{301}lie");
+        assert!(nfc.is_nfc());
+        assert!(!nfc.is_nfd());
+        assert!(nfd.is_nfd());
+        assert!(!nfd.is_nfc());
+        assert!(nfd.nfc().eq(nfc.chars()));
+        assert!(nfc.nfd().eq(nfd.chars()));
+        assert!(nfc.nfd().eq(nfd.nfkd()));
+    }
+
+    #[test]
+    #[cfg(feature = "normalization")]
+    fn utf32_normalization() {
+        let s = utf32str!("\u{fb01}le \u{212b}");
+        assert_eq!(s.to_nfc(), utf32str!("\u{fb01}le \u{c5}"));
+        assert_eq!(s.to_nfd(), utf32str!("\u{fb01}le A\u{30a}"));
+        assert_eq!(s.to_nfkc(), utf32str!("file \u{c5}"));
+        assert_eq!(s.to_nfkd(), utf32str!("file A\u{30a}"));
+        assert!(s.to_nfkc().is_nfkc());
+        assert!(!s.is_nfkd());
+    }
+
+    #[test]
+    #[cfg(feature = "segmentation")]
+    fn utf16_segmentation() {
+        use alloc::vec::Vec;
+
+        let s = utf16str!("a\u{310}e\u{301}o\u{308}\u{332}🇷🇸🇮🇴");
+        assert_eq!(
+            s.grapheme_indices(true).collect::<Vec<_>>(),
+            [
+                (0, utf16str!("a\u{310}")),
+                (2, utf16str!("e\u{301}")),
+                (4, utf16str!("o\u{308}\u{332}")),
+                (7, utf16str!("🇷🇸")),
+                (11, utf16str!("🇮🇴")),
+            ]
+        );
+        assert_eq!(s.graphemes(true).next_back(), Some(utf16str!("🇮🇴")));
+
+        let s = utf16str!("The quick (\"brown\") fox can't jump 32.3 feet, right?");
+        assert_eq!(
+            s.unicode_words().collect::<Vec<_>>(),
+            ["The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"]
+        );
+        assert_eq!(
+            s.split_word_bounds().take(5).collect::<Vec<_>>(),
+            ["The", " ", "quick", " ", "("]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "segmentation")]
+    fn utf32_segmentation() {
+        use alloc::vec::Vec;
+
+        let s = utf32str!("👨‍👩‍👧 ok𝄞");
+        assert_eq!(
+            s.grapheme_indices(true).collect::<Vec<_>>(),
+            [
+                (0, utf32str!("👨‍👩‍👧")),
+                (5, utf32str!(" ")),
+                (6, utf32str!("o")),
+                (7, utf32str!("k")),
+                (8, utf32str!("𝄞")),
+            ]
+        );
+        assert_eq!(
+            s.split_word_bounds().collect::<Vec<_>>(),
+            [
+                utf32str!("👨‍👩‍👧"),
+                utf32str!(" "),
+                utf32str!("ok"),
+                utf32str!("𝄞")
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "segmentation")]
+    fn lazy_segmentation() {
+        use alloc::vec::Vec;
+
+        let mut s = Utf16String::from_str("e\u{301}🇷🇸 The quick\n");
+        for _ in 0..100_000 {
+            s.push_utfstr(utf16str!("brown fox "));
+        }
+        s.push_utfstr(utf16str!("jumps🇮🇴"));
+        assert_eq!(
+            s.graphemes(true).take(3).collect::<Vec<_>>(),
+            [utf16str!("e\u{301}"), utf16str!("🇷🇸"), utf16str!(" ")]
+        );
+        assert_eq!(
+            s.grapheme_indices(true).rev().take(2).collect::<Vec<_>>(),
+            [
+                (s.len() - 4, utf16str!("🇮🇴")),
+                (s.len() - 5, utf16str!("s"))
+            ]
+        );
+        assert_eq!(
+            s.unicode_words().take(3).collect::<Vec<_>>(),
+            ["e\u{301}", "The", "quick"]
+        );
+        assert_eq!(
+            s.split_word_bounds().rev().take(3).collect::<Vec<_>>(),
+            ["🇮🇴", "jumps", " "]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "segmentation")]
+    fn mixed_word_segmentation() {
+        use alloc::{string::String, vec::Vec};
+
+        // Word boundaries of this string differ between forward and backward iteration
+        let text = "🇺ᆨ'\u{200d}💖";
+        let s = Utf16String::from_str(text);
+        let mut iter = s.split_word_bounds();
+        let (mut front, mut back) = (String::new(), Vec::new());
+        front.extend(iter.next().unwrap().chars());
+        back.push(iter.next_back().unwrap().to_string());
+        for segment in &mut iter {
+            front.extend(segment.chars());
+        }
+        assert_eq!(iter.next_back(), None);
+        front.extend(back.iter().rev().map(String::as_str));
+        assert_eq!(front, text);
+
+        let s = Utf32String::from_str(text);
+        let mut iter = s.split_word_bounds();
+        let (mut front, mut back) = (String::new(), Vec::new());
+        front.extend(iter.next().unwrap().chars());
+        back.push(iter.next_back().unwrap().to_string());
+        for segment in &mut iter {
+            front.extend(segment.chars());
+        }
+        front.extend(back.iter().rev().map(String::as_str));
+        assert_eq!(front, text);
+
+        let mut words = s.unicode_words();
+        words.next();
+        words.next_back();
+        assert!(words.all(|w| !w.is_empty()));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn utf32_bytes() {
+        use crate::error::FromBytesError;
+
+        let s = utf32str!("a💖");
+        let bytes = s.to_be_bytes();
+        assert_eq!(bytes, [0, 0, 0, 0x61, 0, 0x01, 0xF4, 0x96]);
+        assert_eq!(Utf32String::from_utf32be_bytes(&bytes).unwrap(), s);
+        assert_eq!(
+            Utf32String::from_utf32le_bytes(&s.to_le_bytes()).unwrap(),
+            s
+        );
+        assert!(matches!(
+            Utf32String::from_utf32le_bytes(&bytes[..7]),
+            Err(FromBytesError::InvalidByteLength(_))
+        ));
+        assert!(matches!(
+            Utf32String::from_utf32le_bytes(&[0, 0xD8, 0, 0]),
+            Err(FromBytesError::InvalidUtf(_))
+        ));
+        assert!(matches!(
+            Utf16String::from_utf16be_bytes(&[0xD8, 0]),
+            Err(FromBytesError::InvalidUtf(_))
+        ));
+    }
+
+    #[test]
+    fn encode_into() {
+        let s = utf16str!("a\u{e9}\u{1F496}");
+        let mut bytes = [0; 8];
+        assert_eq!(s.encode_utf8_into(&mut bytes), Ok(7));
+        assert_eq!(&bytes[..7], "a\u{e9}\u{1F496}".as_bytes());
+        assert_eq!(
+            s.encode_utf8_into(&mut bytes[..3]).unwrap_err().required(),
+            7
+        );
+        assert_eq!(s.encode_utf8_into(&mut []).unwrap_err().required(), 7);
+
+        let mut units = [0; 3];
+        assert_eq!(s.encode_utf32_into(&mut units), Ok(3));
+        assert_eq!(units, [0x61, 0xE9, 0x1F496]);
+
+        let s = utf32str!("a\u{1F496}");
+        let mut units = [0; 3];
+        assert_eq!(s.encode_utf16_into(&mut units), Ok(3));
+        assert_eq!(units, [0x61, 0xD83D, 0xDC96]);
+        assert_eq!(
+            s.encode_utf16_into(&mut units[..2]).unwrap_err().required(),
+            3
+        );
+
+        let mut buf = [0; 4];
+        let s = Utf16Str::from_str_into("a\u{1F496}", &mut buf).unwrap();
+        assert_eq!(s, utf16str!("a\u{1F496}"));
+        assert_eq!(
+            Utf16Str::from_str_into("abcde", &mut buf)
+                .unwrap_err()
+                .required(),
+            5
+        );
+        let mut buf = [0; 2];
+        let s = Utf32Str::from_str_into("a\u{1F496}", &mut buf).unwrap();
+        assert_eq!(s, utf32str!("a\u{1F496}"));
+    }
+
+    #[test]
+    fn transcoded_lengths() {
+        for s in [
+            "",
+            "abc",
+            "\u{7F}\u{80}\u{7FF}\u{800}",
+            "\u{FFFF}\u{10000}\u{10FFFF}",
+            "a💖",
+        ] {
+            let mut buf16 = [0; 16];
+            let mut buf32 = [0; 16];
+            let s16 = Utf16Str::from_str_into(s, &mut buf16).unwrap();
+            let s32 = Utf32Str::from_str_into(s, &mut buf32).unwrap();
+            assert_eq!(s16.utf8_len(), s.len());
+            assert_eq!(s32.utf8_len(), s.len());
+            assert_eq!(s32.utf16_len(), s16.len());
+            assert_eq!(crate::utf16_len(s), s16.len());
+        }
+    }
 }
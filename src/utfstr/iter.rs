@@ -1,12 +1,18 @@
 use crate::{
     debug_fmt_char_iter, decode_utf16, decode_utf32,
     iter::{DecodeUtf16, DecodeUtf32},
+    pattern::Pattern,
+    Utf16Str, Utf32Str,
 };
+#[cfg(feature = "segmentation")]
+use alloc::string::String;
 use core::{
     fmt::Write,
     iter::{Copied, DoubleEndedIterator, ExactSizeIterator, FlatMap, FusedIterator},
     slice::Iter,
 };
+#[cfg(feature = "segmentation")]
+use unicode_segmentation::{GraphemeCursor, GraphemeIncomplete, UnicodeSegmentation};
 
 /// An iterator over the [`char`]s of a UTF-16 string slice
 ///
@@ -408,3 +414,1056 @@ impl<'a> ExactSizeIterator for CodeUnits<'a> {
         self.iter.len()
     }
 }
+
+/// Shared state of the splitting iterators of UTF string slices.
+#[derive(Debug, Clone)]
+struct SplitInternal<'a, S: ?Sized, P> {
+    haystack: &'a S,
+    matcher: P,
+    start: usize,
+    end: usize,
+    position: Option<usize>,
+    back_position: Option<usize>,
+    allow_trailing_empty: bool,
+    inclusive: bool,
+    finished: bool,
+}
+
+impl<'a, S: ?Sized, P> SplitInternal<'a, S, P> {
+    fn new(haystack: &'a S, matcher: P, len: usize) -> Self {
+        Self {
+            haystack,
+            matcher,
+            start: 0,
+            end: len,
+            position: Some(0),
+            back_position: Some(len),
+            allow_trailing_empty: true,
+            inclusive: false,
+            finished: false,
+        }
+    }
+}
+
+macro_rules! split_internal_impl {
+    ($($utfstr:ident),+) => {$(
+        impl<'a, P: Pattern<$utfstr>> SplitInternal<'a, $utfstr, P> {
+            #[inline]
+            fn get_end(&mut self) -> Option<&'a $utfstr> {
+                if !self.finished {
+                    self.finished = true;
+                    if self.allow_trailing_empty || self.end > self.start {
+                        return Some(&self.haystack[self.start..self.end]);
+                    }
+                }
+                None
+            }
+
+            fn next(&mut self) -> Option<&'a $utfstr> {
+                if self.finished {
+                    return None;
+                }
+                if let Some(position) = self.position {
+                    let haystack = &self.haystack[position..self.end];
+                    if let Some((a, b)) = self.matcher.find_in(haystack) {
+                        let (a, b) = (position + a, position + b);
+                        let piece = if self.inclusive {
+                            &self.haystack[self.start..b]
+                        } else {
+                            &self.haystack[self.start..a]
+                        };
+                        self.start = b;
+                        self.position = if a == b {
+                            // Empty match, so resume searching after the next char
+                            let mut iter = self.haystack[b..self.end].char_indices();
+                            iter.next().map(|_| b + iter.offset())
+                        } else {
+                            Some(b)
+                        };
+                        return Some(piece);
+                    }
+                    self.position = None;
+                }
+                self.get_end()
+            }
+
+            fn next_back(&mut self) -> Option<&'a $utfstr> {
+                if self.finished {
+                    return None;
+                }
+                if let Some(position) = self.back_position {
+                    let haystack = &self.haystack[self.start..position];
+                    if let Some((a, b)) = self.matcher.rfind_in(haystack) {
+                        let (a, b) = (self.start + a, self.start + b);
+                        let piece = &self.haystack[b..self.end];
+                        self.end = a;
+                        self.back_position = if a == b {
+                            // Empty match, so resume searching before the previous char
+                            self.haystack[self.start..a]
+                                .char_indices()
+                                .next_back()
+                                .map(|(i, _)| self.start + i)
+                        } else {
+                            Some(a)
+                        };
+                        return Some(piece);
+                    }
+                    self.back_position = None;
+                }
+                self.get_end()
+            }
+        }
+    )+};
+}
+
+split_internal_impl!(Utf16Str, Utf32Str);
+
+/// An iterator over substrings of a UTF string slice, separated by characters matched by a
+/// pattern.
+///
+/// This struct is created by the [`split`][crate::Utf16Str::split] method on
+/// [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct Split<'a, S: ?Sized, P> {
+    inner: SplitInternal<'a, S, P>,
+}
+
+/// An iterator over substrings of a UTF string slice, separated by characters matched by a
+/// pattern and yielded in reverse order.
+///
+/// This struct is created by the [`rsplit`][crate::Utf16Str::rsplit] method on
+/// [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct RSplit<'a, S: ?Sized, P> {
+    inner: SplitInternal<'a, S, P>,
+}
+
+/// An iterator over substrings of a UTF string slice, separated by characters matched by a
+/// pattern, and restricted to returning at most `n` items.
+///
+/// This struct is created by the [`splitn`][crate::Utf16Str::splitn] method on
+/// [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct SplitN<'a, S: ?Sized, P> {
+    inner: SplitInternal<'a, S, P>,
+    count: usize,
+}
+
+/// An iterator over substrings of a UTF string slice, separated by characters matched by a
+/// pattern, starting from the end of the string and restricted to returning at most `n` items.
+///
+/// This struct is created by the [`rsplitn`][crate::Utf16Str::rsplitn] method on
+/// [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct RSplitN<'a, S: ?Sized, P> {
+    inner: SplitInternal<'a, S, P>,
+    count: usize,
+}
+
+/// An iterator over substrings of a UTF string slice, separated by characters matched by a
+/// pattern, skipping a trailing empty substring.
+///
+/// This struct is created by the [`split_terminator`][crate::Utf16Str::split_terminator] method
+/// on [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct SplitTerminator<'a, S: ?Sized, P> {
+    inner: SplitInternal<'a, S, P>,
+}
+
+/// An iterator over substrings of a UTF string slice, each terminated by the characters matched
+/// by a pattern.
+///
+/// This struct is created by the [`split_inclusive`][crate::Utf16Str::split_inclusive] method on
+/// [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct SplitInclusive<'a, S: ?Sized, P> {
+    inner: SplitInternal<'a, S, P>,
+}
+
+impl<'a, S: ?Sized, P> Split<'a, S, P> {
+    pub(super) fn new(haystack: &'a S, pat: P, len: usize) -> Self {
+        Self {
+            inner: SplitInternal::new(haystack, pat, len),
+        }
+    }
+}
+
+impl<'a, S: ?Sized, P> RSplit<'a, S, P> {
+    pub(super) fn new(haystack: &'a S, pat: P, len: usize) -> Self {
+        Self {
+            inner: SplitInternal::new(haystack, pat, len),
+        }
+    }
+}
+
+impl<'a, S: ?Sized, P> SplitN<'a, S, P> {
+    pub(super) fn new(haystack: &'a S, pat: P, len: usize, count: usize) -> Self {
+        Self {
+            inner: SplitInternal::new(haystack, pat, len),
+            count,
+        }
+    }
+}
+
+impl<'a, S: ?Sized, P> RSplitN<'a, S, P> {
+    pub(super) fn new(haystack: &'a S, pat: P, len: usize, count: usize) -> Self {
+        Self {
+            inner: SplitInternal::new(haystack, pat, len),
+            count,
+        }
+    }
+}
+
+impl<'a, S: ?Sized, P> SplitTerminator<'a, S, P> {
+    pub(super) fn new(haystack: &'a S, pat: P, len: usize) -> Self {
+        let mut inner = SplitInternal::new(haystack, pat, len);
+        inner.allow_trailing_empty = false;
+        Self { inner }
+    }
+}
+
+impl<'a, S: ?Sized, P> SplitInclusive<'a, S, P> {
+    pub(super) fn new(haystack: &'a S, pat: P, len: usize) -> Self {
+        let mut inner = SplitInternal::new(haystack, pat, len);
+        inner.allow_trailing_empty = false;
+        inner.inclusive = true;
+        Self { inner }
+    }
+}
+
+macro_rules! split_impls {
+    ($($utfstr:ident),+) => {$(
+        impl<'a, P: Pattern<$utfstr>> Iterator for Split<'a, $utfstr, P> {
+            type Item = &'a $utfstr;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next()
+            }
+        }
+
+        impl<'a, P: Pattern<$utfstr>> FusedIterator for Split<'a, $utfstr, P> {}
+
+        impl<'a, P: Pattern<$utfstr>> Iterator for RSplit<'a, $utfstr, P> {
+            type Item = &'a $utfstr;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next_back()
+            }
+        }
+
+        impl<'a, P: Pattern<$utfstr>> FusedIterator for RSplit<'a, $utfstr, P> {}
+
+        impl<'a, P: Pattern<$utfstr>> Iterator for SplitN<'a, $utfstr, P> {
+            type Item = &'a $utfstr;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                match self.count {
+                    0 => None,
+                    1 => {
+                        self.count = 0;
+                        self.inner.get_end()
+                    }
+                    _ => {
+                        self.count -= 1;
+                        self.inner.next()
+                    }
+                }
+            }
+        }
+
+        impl<'a, P: Pattern<$utfstr>> FusedIterator for SplitN<'a, $utfstr, P> {}
+
+        impl<'a, P: Pattern<$utfstr>> Iterator for RSplitN<'a, $utfstr, P> {
+            type Item = &'a $utfstr;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                match self.count {
+                    0 => None,
+                    1 => {
+                        self.count = 0;
+                        self.inner.get_end()
+                    }
+                    _ => {
+                        self.count -= 1;
+                        self.inner.next_back()
+                    }
+                }
+            }
+        }
+
+        impl<'a, P: Pattern<$utfstr>> FusedIterator for RSplitN<'a, $utfstr, P> {}
+
+        impl<'a, P: Pattern<$utfstr>> Iterator for SplitTerminator<'a, $utfstr, P> {
+            type Item = &'a $utfstr;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next()
+            }
+        }
+
+        impl<'a, P: Pattern<$utfstr>> FusedIterator for SplitTerminator<'a, $utfstr, P> {}
+
+        impl<'a, P: Pattern<$utfstr>> Iterator for SplitInclusive<'a, $utfstr, P> {
+            type Item = &'a $utfstr;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next()
+            }
+        }
+
+        impl<'a, P: Pattern<$utfstr>> FusedIterator for SplitInclusive<'a, $utfstr, P> {}
+    )+};
+}
+
+split_impls!(Utf16Str, Utf32Str);
+
+/// An iterator over the lines of a UTF string slice.
+///
+/// This struct is created by the [`lines`][crate::Utf16Str::lines] method on
+/// [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct Lines<'a, S: ?Sized> {
+    iter: SplitInclusive<'a, S, char>,
+}
+
+impl<'a, S: ?Sized> Lines<'a, S> {
+    pub(super) fn new(s: &'a S, len: usize) -> Self {
+        Self {
+            iter: SplitInclusive::new(s, '\n', len),
+        }
+    }
+}
+
+/// An iterator over the non-whitespace substrings of a UTF string slice, separated by any amount
+/// of whitespace.
+///
+/// This struct is created by the [`split_whitespace`][crate::Utf16Str::split_whitespace] method
+/// on [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct SplitWhitespace<'a, S: ?Sized> {
+    iter: Split<'a, S, fn(char) -> bool>,
+}
+
+impl<'a, S: ?Sized> SplitWhitespace<'a, S> {
+    pub(super) fn new(s: &'a S, len: usize) -> Self {
+        Self {
+            iter: Split::new(s, char::is_whitespace, len),
+        }
+    }
+}
+
+/// An iterator over the non-ASCII-whitespace substrings of a UTF string slice, separated by any
+/// amount of ASCII whitespace.
+///
+/// This struct is created by the
+/// [`split_ascii_whitespace`][crate::Utf16Str::split_ascii_whitespace] method on
+/// [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct SplitAsciiWhitespace<'a, S: ?Sized> {
+    iter: Split<'a, S, fn(char) -> bool>,
+}
+
+impl<'a, S: ?Sized> SplitAsciiWhitespace<'a, S> {
+    pub(super) fn new(s: &'a S, len: usize) -> Self {
+        Self {
+            iter: Split::new(s, |c| c.is_ascii_whitespace(), len),
+        }
+    }
+}
+
+macro_rules! lines_impls {
+    ($($utfstr:ident),+) => {$(
+        impl<'a> Iterator for Lines<'a, $utfstr> {
+            type Item = &'a $utfstr;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let mut line = self.iter.next()?;
+                if line.ends_with('\n') {
+                    line = &line[..line.len() - 1];
+                    if line.ends_with('\r') {
+                        line = &line[..line.len() - 1];
+                    }
+                }
+                Some(line)
+            }
+        }
+
+        impl<'a> FusedIterator for Lines<'a, $utfstr> {}
+
+        impl<'a> Iterator for SplitWhitespace<'a, $utfstr> {
+            type Item = &'a $utfstr;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.find(|s| !s.is_empty())
+            }
+        }
+
+        impl<'a> FusedIterator for SplitWhitespace<'a, $utfstr> {}
+
+        impl<'a> Iterator for SplitAsciiWhitespace<'a, $utfstr> {
+            type Item = &'a $utfstr;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.find(|s| !s.is_empty())
+            }
+        }
+
+        impl<'a> FusedIterator for SplitAsciiWhitespace<'a, $utfstr> {}
+    )+};
+}
+
+lines_impls!(Utf16Str, Utf32Str);
+
+/// Shared state of the match iterators of UTF string slices.
+#[derive(Debug, Clone)]
+struct MatchIndicesInternal<'a, S: ?Sized, P> {
+    haystack: &'a S,
+    matcher: P,
+    position: Option<usize>,
+    back_position: Option<usize>,
+}
+
+impl<'a, S: ?Sized, P> MatchIndicesInternal<'a, S, P> {
+    fn new(haystack: &'a S, matcher: P, len: usize) -> Self {
+        Self {
+            haystack,
+            matcher,
+            position: Some(0),
+            back_position: Some(len),
+        }
+    }
+}
+
+macro_rules! match_indices_internal_impl {
+    ($($utfstr:ident),+) => {$(
+        impl<'a, P: Pattern<$utfstr>> MatchIndicesInternal<'a, $utfstr, P> {
+            fn next(&mut self) -> Option<(usize, &'a $utfstr)> {
+                let position = self.position?;
+                let haystack = &self.haystack[position..];
+                if let Some((a, b)) = self.matcher.find_in(haystack) {
+                    let (a, b) = (position + a, position + b);
+                    self.position = if a == b {
+                        // Empty match, so resume searching after the next char
+                        let mut iter = self.haystack[b..].char_indices();
+                        iter.next().map(|_| b + iter.offset())
+                    } else {
+                        Some(b)
+                    };
+                    return Some((a, &self.haystack[a..b]));
+                }
+                self.position = None;
+                None
+            }
+
+            fn next_back(&mut self) -> Option<(usize, &'a $utfstr)> {
+                let position = self.back_position?;
+                let haystack = &self.haystack[..position];
+                if let Some((a, b)) = self.matcher.rfind_in(haystack) {
+                    self.back_position = if a == b {
+                        // Empty match, so resume searching before the previous char
+                        self.haystack[..a].char_indices().next_back().map(|(i, _)| i)
+                    } else {
+                        Some(a)
+                    };
+                    return Some((a, &self.haystack[a..b]));
+                }
+                self.back_position = None;
+                None
+            }
+        }
+    )+};
+}
+
+match_indices_internal_impl!(Utf16Str, Utf32Str);
+
+/// An iterator over the matches of a pattern within a UTF string slice.
+///
+/// This struct is created by the [`matches`][crate::Utf16Str::matches] method on
+/// [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct Matches<'a, S: ?Sized, P> {
+    inner: MatchIndicesInternal<'a, S, P>,
+}
+
+/// An iterator over the matches of a pattern within a UTF string slice, yielded in reverse order.
+///
+/// This struct is created by the [`rmatches`][crate::Utf16Str::rmatches] method on
+/// [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct RMatches<'a, S: ?Sized, P> {
+    inner: MatchIndicesInternal<'a, S, P>,
+}
+
+/// An iterator over the matches of a pattern within a UTF string slice, and the index that each
+/// match starts at.
+///
+/// This struct is created by the [`match_indices`][crate::Utf16Str::match_indices] method on
+/// [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct MatchIndices<'a, S: ?Sized, P> {
+    inner: MatchIndicesInternal<'a, S, P>,
+}
+
+/// An iterator over the matches of a pattern within a UTF string slice, and the index that each
+/// match starts at, yielded in reverse order.
+///
+/// This struct is created by the [`rmatch_indices`][crate::Utf16Str::rmatch_indices] method on
+/// [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct RMatchIndices<'a, S: ?Sized, P> {
+    inner: MatchIndicesInternal<'a, S, P>,
+}
+
+macro_rules! match_iter_new {
+    ($($name:ident),+) => {$(
+        impl<'a, S: ?Sized, P> $name<'a, S, P> {
+            pub(super) fn new(haystack: &'a S, pat: P, len: usize) -> Self {
+                Self {
+                    inner: MatchIndicesInternal::new(haystack, pat, len),
+                }
+            }
+        }
+    )+};
+}
+
+match_iter_new!(Matches, RMatches, MatchIndices, RMatchIndices);
+
+macro_rules! match_impls {
+    ($($utfstr:ident),+) => {$(
+        impl<'a, P: Pattern<$utfstr>> Iterator for Matches<'a, $utfstr, P> {
+            type Item = &'a $utfstr;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next().map(|(_, s)| s)
+            }
+        }
+
+        impl<'a, P: Pattern<$utfstr>> FusedIterator for Matches<'a, $utfstr, P> {}
+
+        impl<'a, P: Pattern<$utfstr>> Iterator for RMatches<'a, $utfstr, P> {
+            type Item = &'a $utfstr;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next_back().map(|(_, s)| s)
+            }
+        }
+
+        impl<'a, P: Pattern<$utfstr>> FusedIterator for RMatches<'a, $utfstr, P> {}
+
+        impl<'a, P: Pattern<$utfstr>> Iterator for MatchIndices<'a, $utfstr, P> {
+            type Item = (usize, &'a $utfstr);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next()
+            }
+        }
+
+        impl<'a, P: Pattern<$utfstr>> FusedIterator for MatchIndices<'a, $utfstr, P> {}
+
+        impl<'a, P: Pattern<$utfstr>> Iterator for RMatchIndices<'a, $utfstr, P> {
+            type Item = (usize, &'a $utfstr);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next_back()
+            }
+        }
+
+        impl<'a, P: Pattern<$utfstr>> FusedIterator for RMatchIndices<'a, $utfstr, P> {}
+    )+};
+}
+
+match_impls!(Utf16Str, Utf32Str);
+
+/// Returns whether there is a word boundary between two adjacent code units regardless of the
+/// text around them.
+///
+/// This holds for line breaks (WB3a, WB3b) and for ASCII spaces next to other ASCII characters
+/// (WB3d, WB999), since no rule can join them with the characters around them. Word segmentation
+/// starting or stopping at such a boundary gives the same result as segmenting the whole text.
+#[cfg(feature = "segmentation")]
+fn is_word_break_point(prev: u32, next: u32) -> bool {
+    const LF: u32 = 0x0A;
+    const CR: u32 = 0x0D;
+    const SPACE: u32 = 0x20;
+    match (prev, next) {
+        (CR, LF) | (SPACE, SPACE) => false,
+        (CR | LF, _) | (_, CR | LF) => true,
+        (SPACE, 0..=0x7F) | (0..=0x7F, SPACE) => true,
+        _ => false,
+    }
+}
+
+/// Shared state of the word segmentation iterators of UTF string slices.
+///
+/// Words are segmented lazily: only the text up to the next certain word boundary (see
+/// [`is_word_break_point`]) from either end is transcoded to UTF-8, into buffers that are reused.
+#[cfg(feature = "segmentation")]
+#[derive(Debug, Clone)]
+struct WordSegments<'a, S: ?Sized> {
+    haystack: &'a S,
+    front: usize,
+    back: usize,
+    front_buf: String,
+    /// Bytes of `front_buf` that have already been yielded.
+    front_used: usize,
+    back_buf: String,
+    /// Bytes of `back_buf` that have not been yielded yet.
+    back_left: usize,
+}
+
+/// An iterator over the grapheme clusters of a UTF string slice.
+///
+/// This struct is created by the [`graphemes`][crate::Utf16Str::graphemes] method on
+/// [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[cfg(feature = "segmentation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "segmentation")))]
+#[derive(Debug, Clone)]
+pub struct Graphemes<'a, S: ?Sized> {
+    haystack: &'a S,
+    front: usize,
+    back: usize,
+    /// Cursor at `front`, in UTF-8 offsets from the start of `haystack`.
+    front_cursor: GraphemeCursor,
+    /// Cursor at `back`, in UTF-8 offsets shifted by an unknown amount until the start of
+    /// `haystack` is reached.
+    back_cursor: GraphemeCursor,
+    is_extended: bool,
+}
+
+/// An iterator over the grapheme clusters of a UTF string slice, and their positions.
+///
+/// This struct is created by the [`grapheme_indices`][crate::Utf16Str::grapheme_indices] method
+/// on [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[cfg(feature = "segmentation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "segmentation")))]
+#[derive(Debug, Clone)]
+pub struct GraphemeIndices<'a, S: ?Sized> {
+    inner: Graphemes<'a, S>,
+}
+
+/// An iterator over the words of a UTF string slice.
+///
+/// This struct is created by the [`unicode_words`][crate::Utf16Str::unicode_words] method on
+/// [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its documentation for
+/// more.
+#[cfg(feature = "segmentation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "segmentation")))]
+#[derive(Debug, Clone)]
+pub struct UnicodeWords<'a, S: ?Sized> {
+    inner: WordSegments<'a, S>,
+}
+
+/// An iterator over the substrings of a UTF string slice, separated on word boundaries.
+///
+/// This struct is created by the [`split_word_bounds`][crate::Utf16Str::split_word_bounds]
+/// method on [`Utf16Str`][crate::Utf16Str] and [`Utf32Str`][crate::Utf32Str]. See its
+/// documentation for more.
+#[cfg(feature = "segmentation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "segmentation")))]
+#[derive(Debug, Clone)]
+pub struct UWordBounds<'a, S: ?Sized> {
+    inner: WordSegments<'a, S>,
+}
+
+/// Returns whether a word segment is a word for [`UnicodeWords`].
+#[cfg(feature = "segmentation")]
+fn is_word(segment: &str) -> bool {
+    segment.unicode_words().next().is_some()
+}
+
+macro_rules! segment_impls {
+    ($($utfstr:ident, $char_len:expr, $str_len:expr;)+) => {$(
+        #[cfg(feature = "segmentation")]
+        impl<'a> WordSegments<'a, $utfstr> {
+            fn new(s: &'a $utfstr) -> Self {
+                Self {
+                    haystack: s,
+                    front: 0,
+                    back: s.len(),
+                    front_buf: String::new(),
+                    front_used: 0,
+                    back_buf: String::new(),
+                    back_left: 0,
+                }
+            }
+
+            /// Returns the next segment for which `filter` returns `true` for its UTF-8 text.
+            fn next(&mut self, filter: fn(&str) -> bool) -> Option<(usize, &'a $utfstr)> {
+                while self.front != self.back {
+                    if self.front_used == self.front_buf.len() {
+                        let units = self.haystack.as_slice();
+                        let end = (self.front + 1..self.back)
+                            .find(|&i| is_word_break_point(units[i - 1].into(), units[i].into()))
+                            .unwrap_or(self.back);
+                        self.front_buf.clear();
+                        self.front_buf.extend(self.haystack[self.front..end].chars());
+                        self.front_used = 0;
+                    }
+                    let mut segment =
+                        self.front_buf[self.front_used..].split_word_bounds().next()?;
+                    self.front_used += segment.len();
+                    let start = self.front;
+                    let len = $str_len(segment);
+                    if len > self.back - start {
+                        // Boundaries can differ between the two directions, so the segment
+                        // may cross the back end, where it must stop
+                        let (mut units, mut end8) = (0, 0);
+                        for c in segment.chars() {
+                            if units == self.back - start {
+                                break;
+                            }
+                            units += $char_len(c);
+                            end8 += c.len_utf8();
+                        }
+                        segment = &segment[..end8];
+                        // Drop the rest of the buffer, which is past the back end
+                        self.front_used = self.front_buf.len();
+                        self.front = self.back;
+                    } else {
+                        self.front += len;
+                    }
+                    if filter(segment) {
+                        return Some((start, &self.haystack[start..self.front]));
+                    }
+                }
+                None
+            }
+
+            /// Returns the next segment from the back for which `filter` returns `true` for its
+            /// UTF-8 text.
+            fn next_back(&mut self, filter: fn(&str) -> bool) -> Option<(usize, &'a $utfstr)> {
+                while self.front != self.back {
+                    if self.back_left == 0 {
+                        let units = self.haystack.as_slice();
+                        let start = (self.front + 1..self.back)
+                            .rev()
+                            .find(|&i| is_word_break_point(units[i - 1].into(), units[i].into()))
+                            .unwrap_or(self.front);
+                        self.back_buf.clear();
+                        self.back_buf.extend(self.haystack[start..self.back].chars());
+                        self.back_left = self.back_buf.len();
+                    }
+                    let mut segment = self.back_buf[..self.back_left]
+                        .split_word_bounds()
+                        .next_back()?;
+                    self.back_left -= segment.len();
+                    let end = self.back;
+                    let len = $str_len(segment);
+                    if len > end - self.front {
+                        // The segment may cross the front end, where it must stop
+                        let (mut units, mut start8) = (0, segment.len());
+                        for c in segment.chars().rev() {
+                            if units == end - self.front {
+                                break;
+                            }
+                            units += $char_len(c);
+                            start8 -= c.len_utf8();
+                        }
+                        segment = &segment[start8..];
+                        // Drop the rest of the buffer, which is before the front end
+                        self.back_left = 0;
+                        self.back = self.front;
+                    } else {
+                        self.back -= len;
+                    }
+                    if filter(segment) {
+                        return Some((self.back, &self.haystack[self.back..end]));
+                    }
+                }
+                None
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.back - self.front;
+                (usize::from(len != 0), Some(len))
+            }
+        }
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> Graphemes<'a, $utfstr> {
+            pub(super) fn new(s: &'a $utfstr, is_extended: bool) -> Self {
+                Self {
+                    haystack: s,
+                    front: 0,
+                    back: s.len(),
+                    front_cursor: GraphemeCursor::new(0, usize::MAX, is_extended),
+                    back_cursor: GraphemeCursor::new(usize::MAX, usize::MAX, is_extended),
+                    is_extended,
+                }
+            }
+
+            /// Returns the character ending at code unit offset `i` and its length in code units.
+            fn char_before(&self, i: usize) -> (char, usize) {
+                let c = self.haystack[..i].chars().next_back().unwrap();
+                (c, $char_len(c))
+            }
+
+            /// Returns the character starting at code unit offset `i` and its length in code
+            /// units.
+            fn char_at(&self, i: usize) -> (char, usize) {
+                let c = self.haystack[i..].chars().next().unwrap();
+                (c, $char_len(c))
+            }
+
+            /// Advances the front cursor to the next grapheme cluster boundary, one character
+            /// at a time, and returns its code unit offset.
+            fn next_boundary(&mut self) -> usize {
+                let mut pos = self.front;
+                let mut pos8 = self.front_cursor.cur_cursor();
+                let mut buf = [0; 8];
+                while pos != self.back {
+                    // Chunks also hold the previous character so the cursor is never at their
+                    // start, where it would request context that it has already seen
+                    let (mut context, mut prev_len8) = (pos, 0);
+                    if pos > 0 {
+                        let (c, len) = self.char_before(pos);
+                        context -= len;
+                        prev_len8 = c.encode_utf8(&mut buf).len();
+                    }
+                    let (c, len) = self.char_at(pos);
+                    let chunk_len = prev_len8 + c.encode_utf8(&mut buf[prev_len8..]).len();
+                    // SAFETY: The buffer holds two encoded characters
+                    let chunk = unsafe { core::str::from_utf8_unchecked(&buf[..chunk_len]) };
+                    let chunk_start = pos8 - prev_len8;
+                    let mut context8 = chunk_start;
+                    loop {
+                        // The cursor is at the start of the last character of the chunk, so
+                        // a boundary is always there
+                        match self.front_cursor.next_boundary(chunk, chunk_start) {
+                            Ok(_) => return pos,
+                            Err(GraphemeIncomplete::NextChunk) => break,
+                            Err(GraphemeIncomplete::PreContext(_)) => {
+                                let (c, len) = self.char_before(context);
+                                context -= len;
+                                context8 -= c.len_utf8();
+                                self.front_cursor
+                                    .provide_context(c.encode_utf8(&mut [0; 4]), context8);
+                            }
+                            Err(_) => unreachable!(),
+                        }
+                    }
+                    pos += len;
+                    pos8 += c.len_utf8();
+                }
+                pos
+            }
+
+            /// Moves the back cursor to the previous grapheme cluster boundary, one character
+            /// at a time, and returns its code unit offset.
+            fn prev_boundary(&mut self) -> usize {
+                let back8 = self.back_cursor.cur_cursor();
+                let mut pos = self.back;
+                let mut pos8 = back8;
+                let mut buf = [0; 4];
+                while pos != self.front {
+                    let (c, len) = self.char_before(pos);
+                    let chunk = c.encode_utf8(&mut buf);
+                    let start8 = pos8 - c.len_utf8();
+                    let (mut context, mut context8) = (pos - len, start8);
+                    loop {
+                        match self.back_cursor.prev_boundary(chunk, start8) {
+                            Ok(Some(b)) if b == start8 => return pos - len,
+                            Ok(_) => return pos,
+                            Err(GraphemeIncomplete::PrevChunk) => break,
+                            Err(GraphemeIncomplete::PreContext(_)) if context == 0 => {
+                                // The context reaches the start of the string, so the real UTF-8
+                                // offsets are known now: restart with them.
+                                let back8 = back8 - context8;
+                                self.back_cursor =
+                                    GraphemeCursor::new(back8, back8, self.is_extended);
+                                return self.prev_boundary();
+                            }
+                            Err(GraphemeIncomplete::PreContext(_)) => {
+                                let (c, len) = self.char_before(context);
+                                context -= len;
+                                context8 -= c.len_utf8();
+                                self.back_cursor
+                                    .provide_context(c.encode_utf8(&mut [0; 4]), context8);
+                            }
+                            Err(_) => unreachable!(),
+                        }
+                    }
+                    pos -= len;
+                    pos8 = start8;
+                }
+                pos
+            }
+        }
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> GraphemeIndices<'a, $utfstr> {
+            pub(super) fn new(s: &'a $utfstr, is_extended: bool) -> Self {
+                Self {
+                    inner: Graphemes::<$utfstr>::new(s, is_extended),
+                }
+            }
+        }
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> UnicodeWords<'a, $utfstr> {
+            pub(super) fn new(s: &'a $utfstr) -> Self {
+                Self {
+                    inner: WordSegments::<$utfstr>::new(s),
+                }
+            }
+        }
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> UWordBounds<'a, $utfstr> {
+            pub(super) fn new(s: &'a $utfstr) -> Self {
+                Self {
+                    inner: WordSegments::<$utfstr>::new(s),
+                }
+            }
+        }
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> Iterator for Graphemes<'a, $utfstr> {
+            type Item = &'a $utfstr;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.front == self.back {
+                    return None;
+                }
+                let start = self.front;
+                self.front = self.next_boundary();
+                Some(&self.haystack[start..self.front])
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.back - self.front;
+                (usize::from(len != 0), Some(len))
+            }
+        }
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> DoubleEndedIterator for Graphemes<'a, $utfstr> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.front == self.back {
+                    return None;
+                }
+                let end = self.back;
+                self.back = self.prev_boundary();
+                Some(&self.haystack[self.back..end])
+            }
+        }
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> FusedIterator for Graphemes<'a, $utfstr> {}
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> Iterator for GraphemeIndices<'a, $utfstr> {
+            type Item = (usize, &'a $utfstr);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let start = self.inner.front;
+                self.inner.next().map(|s| (start, s))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> DoubleEndedIterator for GraphemeIndices<'a, $utfstr> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let s = self.inner.next_back()?;
+                Some((self.inner.back, s))
+            }
+        }
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> FusedIterator for GraphemeIndices<'a, $utfstr> {}
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> Iterator for UnicodeWords<'a, $utfstr> {
+            type Item = &'a $utfstr;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next(is_word).map(|(_, s)| s)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (0, self.inner.size_hint().1)
+            }
+        }
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> DoubleEndedIterator for UnicodeWords<'a, $utfstr> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back(is_word).map(|(_, s)| s)
+            }
+        }
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> FusedIterator for UnicodeWords<'a, $utfstr> {}
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> Iterator for UWordBounds<'a, $utfstr> {
+            type Item = &'a $utfstr;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next(|_| true).map(|(_, s)| s)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> DoubleEndedIterator for UWordBounds<'a, $utfstr> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back(|_| true).map(|(_, s)| s)
+            }
+        }
+
+        #[cfg(feature = "segmentation")]
+        impl<'a> FusedIterator for UWordBounds<'a, $utfstr> {}
+    )+};
+}
+
+segment_impls! {
+    Utf16Str, char::len_utf16, crate::utf16_len;
+    Utf32Str, |_: char| 1, |s: &str| s.chars().count();
+}
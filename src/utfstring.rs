@@ -3,10 +3,12 @@
 //! This module contains UTF strings and related types.
 
 use crate::{
-    decode_utf16_surrogate_pair,
-    error::{Utf16Error, Utf32Error},
-    is_utf16_low_surrogate, is_utf16_surrogate, validate_utf16, validate_utf16_vec, validate_utf32,
-    validate_utf32_vec, Utf16Str, Utf32Str,
+    decode_utf16_surrogate_pair, decode_utf8_char,
+    error::{FromBytesError, Utf16Error, Utf32Error},
+    is_utf16_low_surrogate, is_utf16_surrogate,
+    pattern::Pattern,
+    units_from_bytes, validate_utf16, validate_utf16_vec, validate_utf32, validate_utf32_vec,
+    Utf16Str, Utf32Str,
 };
 use alloc::{
     borrow::{Cow, ToOwned},
@@ -105,6 +107,54 @@ macro_rules! utfstring_common_impl {
                 string
             }
 
+            /// Decodes a slice of UTF-8 bytes to a string, checking that the bytes are valid UTF-8.
+            ///
+            /// The bytes are validated and transcoded in a single pass, without first converting
+            /// them to a [`str`].
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the bytes are not valid UTF-8, reporting the position of the
+            /// error the same way as [`core::str::from_utf8`].
+            pub fn from_utf8(bytes: &[u8]) -> Result<Self, crate::error::Utf8Error> {
+                let mut string = Self::with_capacity(bytes.len());
+                let mut i = 0;
+                while i < bytes.len() {
+                    match decode_utf8_char(&bytes[i..]) {
+                        Ok((c, len)) => {
+                            string.push(c);
+                            i += len;
+                        }
+                        Err(len) => return Err(crate::error::Utf8Error::new(i, len)),
+                    }
+                }
+                Ok(string)
+            }
+
+            /// Decodes a slice of UTF-8 bytes to a string, replacing invalid UTF-8 sequences with
+            /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER].
+            ///
+            /// The bytes are transcoded in a single pass, and invalid sequences are replaced the
+            /// same way as [`String::from_utf8_lossy`].
+            #[must_use]
+            pub fn from_utf8_lossy(bytes: &[u8]) -> Self {
+                let mut string = Self::with_capacity(bytes.len());
+                let mut i = 0;
+                while i < bytes.len() {
+                    match decode_utf8_char(&bytes[i..]) {
+                        Ok((c, len)) => {
+                            string.push(c);
+                            i += len;
+                        }
+                        Err(len) => {
+                            string.push(char::REPLACEMENT_CHARACTER);
+                            i += len.unwrap_or(bytes.len() - i);
+                        }
+                    }
+                }
+                string
+            }
+
             /// Converts a string into a string slice.
             #[inline]
             #[must_use]
@@ -266,6 +316,32 @@ macro_rules! utfstring_common_impl {
             pub fn push_str<S: AsRef<str> + ?Sized>(&mut self, string: &S) {
                 self.extend(string.as_ref().chars())
             }
+
+            /// Replaces all matches of a pattern in this string with another string slice,
+            /// in-place.
+            ///
+            /// This is the in-place equivalent of `replace` on the string slice. Each match is
+            /// replaced using [`replace_range`][Self::replace_range], so no new string is
+            /// allocated unless the replacements grow the string beyond its capacity.
+            ///
+            /// The [pattern][crate::pattern::Pattern] can be a [`char`], a string slice, a slice
+            /// of [`char`]s, or a function or closure that determines if a character matches.
+            pub fn replace_all<P: Pattern<$utfstr>>(&mut self, mut from: P, to: &$utfstr) {
+                let mut position = 0;
+                while let Some((start, end)) = from.find_in(&self[position..]) {
+                    let (start, end) = (position + start, position + end);
+                    self.replace_range(start..end, to);
+                    position = start + to.len();
+                    if start == end {
+                        // Empty match, so skip over the next char to avoid matching it again
+                        let mut iter = self[position..].char_indices();
+                        if iter.next().is_none() {
+                            break;
+                        }
+                        position += iter.offset();
+                    }
+                }
+            }
         }
 
         impl Add<&$utfstr> for $utfstring {
@@ -1153,6 +1229,39 @@ impl Utf16String {
         Ok(unsafe { Self::from_vec_unchecked(v) })
     }
 
+    /// Converts bytes holding UTF-16 code units in little-endian byte order to a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of `bytes` is not a multiple of the code unit size, or if
+    /// the code units are not valid UTF-16.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use widestring::{utf16str, Utf16String};
+    ///
+    /// let s = Utf16String::from_utf16le_bytes(b"h\0i\0").unwrap();
+    /// assert_eq!(s, utf16str!("hi"));
+    ///
+    /// assert!(Utf16String::from_utf16le_bytes(b"h\0i").is_err());
+    /// ```
+    pub fn from_utf16le_bytes(bytes: &[u8]) -> Result<Self, FromBytesError<Utf16Error>> {
+        let v = units_from_bytes(bytes, u16::from_le_bytes)?;
+        Ok(Self::from_vec(v)?)
+    }
+
+    /// Converts bytes holding UTF-16 code units in big-endian byte order to a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of `bytes` is not a multiple of the code unit size, or if
+    /// the code units are not valid UTF-16.
+    pub fn from_utf16be_bytes(bytes: &[u8]) -> Result<Self, FromBytesError<Utf16Error>> {
+        let v = units_from_bytes(bytes, u16::from_be_bytes)?;
+        Ok(Self::from_vec(v)?)
+    }
+
     /// Converts a slice of [`u16`] data to a string, including invalid characters.
     ///
     /// Since the given [`u16`] slice may not be valid UTF-16, and [`Utf16String`] requires that
@@ -1882,6 +1991,28 @@ impl Utf32String {
         Ok(unsafe { Self::from_vec_unchecked(v) })
     }
 
+    /// Converts bytes holding UTF-32 code units in little-endian byte order to a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of `bytes` is not a multiple of the code unit size, or if
+    /// the code units are not valid UTF-32.
+    pub fn from_utf32le_bytes(bytes: &[u8]) -> Result<Self, FromBytesError<Utf32Error>> {
+        let v = units_from_bytes(bytes, u32::from_le_bytes)?;
+        Ok(Self::from_vec(v)?)
+    }
+
+    /// Converts bytes holding UTF-32 code units in big-endian byte order to a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of `bytes` is not a multiple of the code unit size, or if
+    /// the code units are not valid UTF-32.
+    pub fn from_utf32be_bytes(bytes: &[u8]) -> Result<Self, FromBytesError<Utf32Error>> {
+        let v = units_from_bytes(bytes, u32::from_be_bytes)?;
+        Ok(Self::from_vec(v)?)
+    }
+
     /// Converts a slice of [`u32`] data to a string, including invalid characters.
     ///
     /// Since the given [`u32`] slice may not be valid UTF-32, and [`Utf32String`] requires that
@@ -2647,3 +2778,38 @@ pub type WideUtfString = Utf32String;
 /// `wchar_t` size on platform.
 #[cfg(windows)]
 pub type WideUtfString = Utf16String;
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn from_utf8() {
+        let inputs: &[&[u8]] = &[
+            b"",
+            "a\u{7FF}\u{FFFF}💖".as_bytes(),
+            b"a\x80b",
+            b"\xC0\x80",
+            b"\xE0\x80\x80",
+            b"\xED\xA0\x80",
+            b"\xF4\x90\x80\x80",
+            b"\xF5",
+            b"ab\xF0\x9F\x92",
+            b"\xE2\x82a\xF0\x9F",
+        ];
+        for &bytes in inputs {
+            let expected = core::str::from_utf8(bytes);
+            match Utf16String::from_utf8(bytes) {
+                Ok(s) => assert_eq!(s, expected.unwrap()),
+                Err(e) => assert_eq!(e, expected.unwrap_err().into()),
+            }
+            match Utf32String::from_utf8(bytes) {
+                Ok(s) => assert_eq!(s, expected.unwrap()),
+                Err(e) => assert_eq!(e, expected.unwrap_err().into()),
+            }
+            let lossy = alloc::string::String::from_utf8_lossy(bytes);
+            assert_eq!(Utf16String::from_utf8_lossy(bytes), lossy.as_ref());
+            assert_eq!(Utf32String::from_utf8_lossy(bytes), lossy.as_ref());
+        }
+    }
+}
@@ -0,0 +1,725 @@
+//! WTF-8 string types for storing potentially ill-formed UTF-16 as bytes.
+//!
+//! [WTF-8](https://simonsapin.github.io/wtf-8/) is a superset of UTF-8 that can also encode
+//! unpaired UTF-16 surrogates, in the same way UTF-8 would encode them if they were valid code
+//! points. This allows any sequence of UTF-16 code units, such as a [`U16String`][crate::U16String]
+//! from a Windows API, to be stored losslessly in a compact form that is identical to UTF-8 when
+//! the data is valid UTF-16.
+//!
+//! [`Wtf8Str`] is a borrowed WTF-8 string slice, and [`Wtf8Buf`] is its owned counterpart.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use widestring::{U16Str, Wtf8Buf};
+//!
+//! let wide = U16Str::from_slice(&[0x61, 0xD800, 0x62]);
+//! let wtf8 = Wtf8Buf::from(wide);
+//! assert_eq!(wtf8.as_bytes(), b"a\xED\xA0\x80b");
+//! assert_eq!(wtf8.as_str(), None);
+//! assert_eq!(wtf8.to_string(), "a\u{FFFD}b");
+//! assert_eq!(wtf8.to_u16string(), wide);
+//! # }
+//! ```
+
+#[cfg(feature = "alloc")]
+use crate::{decode_utf16, U16Str, U16String};
+use crate::{error::Wtf8Error, is_utf16_high_surrogate, is_utf16_low_surrogate};
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+    vec::Vec,
+};
+use core::fmt::{self, Write};
+#[cfg(feature = "alloc")]
+use core::{borrow::Borrow, ops::Deref};
+
+/// Encodes potentially ill-formed UTF-16 as WTF-8, appending the bytes to `out`.
+#[cfg(feature = "alloc")]
+pub(crate) fn encode_wtf8(units: impl IntoIterator<Item = u16>, out: &mut Vec<u8>) {
+    let units = units.into_iter();
+    out.reserve(units.size_hint().0);
+    for c in decode_utf16(units) {
+        match c {
+            Ok(c) => out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            Err(e) => {
+                let u = e.unpaired_surrogate();
+                out.extend_from_slice(&[
+                    0xE0 | (u >> 12) as u8,
+                    0x80 | ((u >> 6) & 0x3F) as u8,
+                    0x80 | (u & 0x3F) as u8,
+                ]);
+            }
+        }
+    }
+}
+
+/// Returns the surrogate encoded at the start of `bytes`, if any.
+fn decode_surrogate(bytes: &[u8]) -> Option<u16> {
+    match *bytes {
+        [0xED, b1 @ 0xA0..=0xBF, b2 @ 0x80..=0xBF, ..] => {
+            Some(0xD000 | ((b1 & 0x3F) as u16) << 6 | (b2 & 0x3F) as u16)
+        }
+        _ => None,
+    }
+}
+
+/// Splits valid WTF-8 into its leading UTF-8 text and the surrogate and bytes that follow it, if
+/// any.
+fn split_surrogate(bytes: &[u8]) -> (&str, Option<(u16, &[u8])>) {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => (s, None),
+        Err(e) => {
+            let (valid, rest) = bytes.split_at(e.valid_up_to());
+            // SAFETY: Checked to be valid UTF-8 up to this point
+            let valid = unsafe { core::str::from_utf8_unchecked(valid) };
+            (valid, decode_surrogate(rest).map(|u| (u, &rest[3..])))
+        }
+    }
+}
+
+/// Decodes WTF-8 to potentially ill-formed UTF-16, appending the code units to `out`.
+///
+/// Bytes that are not valid WTF-8 are replaced with
+/// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER].
+#[cfg(feature = "std")]
+pub(crate) fn decode_wtf8(mut bytes: &[u8], out: &mut Vec<u16>) {
+    out.reserve(bytes.len());
+    loop {
+        let (valid, error) = match core::str::from_utf8(bytes) {
+            Ok(s) => (s, None),
+            // SAFETY: Checked to be valid UTF-8 up to this point
+            Err(e) => (
+                unsafe { core::str::from_utf8_unchecked(&bytes[..e.valid_up_to()]) },
+                Some(e),
+            ),
+        };
+        out.extend(valid.encode_utf16());
+        let error = match error {
+            Some(error) => error,
+            None => return,
+        };
+        bytes = &bytes[error.valid_up_to()..];
+        if let Some(u) = decode_surrogate(bytes) {
+            out.push(u);
+            bytes = &bytes[3..];
+            continue;
+        }
+        out.push(char::REPLACEMENT_CHARACTER as u16);
+        let len = match *bytes {
+            // A truncated surrogate is a single invalid sequence, as in UTF-8
+            [0xED, 0xA0..=0xBF, ..] => 2,
+            _ => error.error_len().unwrap_or(bytes.len()),
+        };
+        bytes = &bytes[len..];
+    }
+}
+
+/// WTF-8 string slice for [`Wtf8Buf`].
+///
+/// [`Wtf8Str`] is to [`Wtf8Buf`] as [`str`] is to [`String`][alloc::string::String]. It is
+/// always valid [WTF-8](https://simonsapin.github.io/wtf-8/): valid UTF-8 that may also contain
+/// encoded unpaired surrogates.
+///
+/// Use [`as_str`][Self::as_str] to get the string as a [`str`] when it contains no unpaired
+/// surrogates. The [`Display`][core::fmt::Display] implementation replaces unpaired surrogates
+/// with [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER].
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Wtf8Str {
+    bytes: [u8],
+}
+
+impl Wtf8Str {
+    /// Converts a string slice to a WTF-8 string slice.
+    ///
+    /// This is a cost-free conversion, as valid UTF-8 is always valid WTF-8.
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    #[must_use]
+    pub fn from_str(s: &str) -> &Self {
+        // SAFETY: UTF-8 is always valid WTF-8
+        unsafe { Self::from_bytes_unchecked(s.as_bytes()) }
+    }
+
+    /// Converts a slice of bytes to a WTF-8 string slice, checking that the bytes are valid
+    /// WTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not valid WTF-8. Besides the errors of
+    /// [`core::str::from_utf8`], this includes a surrogate pair encoded as two surrogates, since
+    /// WTF-8 encodes it as a single code point instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use widestring::Wtf8Str;
+    ///
+    /// assert!(Wtf8Str::from_bytes(b"a\xED\xA0\x80").is_ok());
+    /// assert_eq!(Wtf8Str::from_bytes(b"a\xFF").unwrap_err().valid_up_to(), 1);
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, Wtf8Error> {
+        let mut pos = 0;
+        let mut after_lead = false;
+        loop {
+            let error = match core::str::from_utf8(&bytes[pos..]) {
+                Ok(_) => break,
+                Err(error) => error,
+            };
+            let index = pos + error.valid_up_to();
+            match decode_surrogate(&bytes[index..]) {
+                Some(u)
+                    if !(is_utf16_low_surrogate(u) && after_lead && error.valid_up_to() == 0) =>
+                {
+                    after_lead = is_utf16_high_surrogate(u);
+                    pos = index + 3;
+                }
+                _ => return Err(Wtf8Error::new(index)),
+            }
+        }
+        // SAFETY: Checked to be valid WTF-8
+        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    }
+
+    /// Converts a slice of bytes to a WTF-8 string slice without checking that the bytes are
+    /// valid WTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid WTF-8.
+    #[allow(trivial_casts)]
+    #[inline]
+    #[must_use]
+    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes as *const [u8] as *const Self)
+    }
+
+    /// Returns the underlying bytes of the string.
+    #[inline]
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the length of the string in bytes.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the string has a length of zero.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the string as a [`str`] if it contains no unpaired surrogates.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        core::str::from_utf8(&self.bytes).ok()
+    }
+
+    /// Decodes the string to a [`U16String`], restoring any unpaired surrogates.
+    ///
+    /// This conversion is lossless.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn to_u16string(&self) -> U16String {
+        let mut units = Vec::with_capacity(self.len());
+        let mut rest = &self.bytes;
+        loop {
+            let (s, surrogate) = split_surrogate(rest);
+            units.extend(s.encode_utf16());
+            match surrogate {
+                Some((u, next)) => {
+                    units.push(u);
+                    rest = next;
+                }
+                None => break,
+            }
+        }
+        U16String::from_vec(units)
+    }
+
+    /// Converts the string to a [`str`], replacing unpaired surrogates with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][core::char::REPLACEMENT_CHARACTER].
+    ///
+    /// Returns a borrowed [`str`] if the string contains no unpaired surrogates.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        match self.as_str() {
+            Some(s) => Cow::Borrowed(s),
+            None => {
+                let mut s = String::with_capacity(self.len());
+                // Writing to a String never fails
+                let _ = write!(s, "{}", self);
+                Cow::Owned(s)
+            }
+        }
+    }
+
+    /// Returns the encoded high surrogate at the end of the string, if any.
+    #[cfg(feature = "alloc")]
+    fn trailing_high_surrogate(&self) -> Option<u16> {
+        let start = self.len().checked_sub(3)?;
+        decode_surrogate(&self.bytes[start..]).filter(|&u| is_utf16_high_surrogate(u))
+    }
+}
+
+impl AsRef<[u8]> for Wtf8Str {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<Wtf8Str> for Wtf8Str {
+    #[inline]
+    fn as_ref(&self) -> &Wtf8Str {
+        self
+    }
+}
+
+impl AsRef<Wtf8Str> for str {
+    #[inline]
+    fn as_ref(&self) -> &Wtf8Str {
+        Wtf8Str::from_str(self)
+    }
+}
+
+impl fmt::Debug for Wtf8Str {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        let mut rest = &self.bytes;
+        loop {
+            let (s, surrogate) = split_surrogate(rest);
+            s.chars()
+                .flat_map(char::escape_debug)
+                .try_for_each(|c| f.write_char(c))?;
+            match surrogate {
+                Some((u, next)) => {
+                    write!(f, "\\u{{{:x}}}", u)?;
+                    rest = next;
+                }
+                None => break,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+impl fmt::Display for Wtf8Str {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rest = &self.bytes;
+        loop {
+            let (s, surrogate) = split_surrogate(rest);
+            f.write_str(s)?;
+            match surrogate {
+                Some((_, next)) => {
+                    f.write_char(char::REPLACEMENT_CHARACTER)?;
+                    rest = next;
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+impl Default for &Wtf8Str {
+    #[inline]
+    fn default() -> Self {
+        Wtf8Str::from_str("")
+    }
+}
+
+impl<'a> From<&'a str> for &'a Wtf8Str {
+    #[inline]
+    fn from(value: &'a str) -> Self {
+        Wtf8Str::from_str(value)
+    }
+}
+
+impl PartialEq<str> for Wtf8Str {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl PartialEq<&str> for Wtf8Str {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToOwned for Wtf8Str {
+    type Owned = Wtf8Buf;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        Wtf8Buf {
+            bytes: self.bytes.to_vec(),
+        }
+    }
+}
+
+/// An owned, mutable WTF-8 string for storing potentially ill-formed UTF-16.
+///
+/// [`Wtf8Buf`] stores any sequence of UTF-16 code units losslessly as
+/// [WTF-8](https://simonsapin.github.io/wtf-8/) bytes, which are identical to UTF-8 when the data
+/// is valid UTF-16. Convert from a [`U16Str`] with [`From`] and back with
+/// [`to_u16string`][Wtf8Str::to_u16string].
+///
+/// [`Wtf8Buf`] derefs to [`Wtf8Str`], so all of its methods are also available.
+///
+/// # Examples
+///
+/// ```
+/// use widestring::{U16String, Wtf8Buf};
+///
+/// let mut wtf8 = Wtf8Buf::from("a");
+/// wtf8.push_u16str(&U16String::from_vec(vec![0xD83D]));
+/// assert_eq!(wtf8.as_str(), None);
+/// // Completing the surrogate pair makes it valid UTF-8
+/// wtf8.push_u16str(&U16String::from_vec(vec![0xDC96]));
+/// assert_eq!(wtf8.as_str(), Some("a💖"));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Wtf8Buf {
+    bytes: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl Wtf8Buf {
+    /// Creates a new empty string.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { bytes: Vec::new() }
+    }
+
+    /// Creates a new empty string with at least the specified capacity in bytes.
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Converts a vector of bytes to a WTF-8 string, checking that the bytes are valid WTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not valid WTF-8. See [`Wtf8Str::from_bytes`] for more.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, Wtf8Error> {
+        Wtf8Str::from_bytes(&bytes)?;
+        Ok(Self { bytes })
+    }
+
+    /// Converts a vector of bytes to a WTF-8 string without checking that the bytes are valid
+    /// WTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid WTF-8.
+    #[inline]
+    #[must_use]
+    pub unsafe fn from_bytes_unchecked(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    /// Converts a wide string slice to a WTF-8 string, preserving any unpaired surrogates.
+    #[inline]
+    #[must_use]
+    pub fn from_u16str(s: &U16Str) -> Self {
+        let mut buf = Self::with_capacity(s.len());
+        encode_wtf8(s.as_slice().iter().copied(), &mut buf.bytes);
+        buf
+    }
+
+    /// Returns a WTF-8 string slice of the whole string.
+    #[inline]
+    #[must_use]
+    pub fn as_wtf8_str(&self) -> &Wtf8Str {
+        // SAFETY: Always valid WTF-8
+        unsafe { Wtf8Str::from_bytes_unchecked(&self.bytes) }
+    }
+
+    /// Appends a string slice to the end of this string.
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        self.bytes.extend_from_slice(s.as_bytes())
+    }
+
+    /// Appends a [`char`] to the end of this string.
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Appends a wide string slice to the end of this string, preserving any unpaired surrogates.
+    ///
+    /// If this string ends with a high surrogate and `s` starts with a low surrogate, they are
+    /// joined into a single code point, so the result is the same as converting the
+    /// concatenated wide strings.
+    pub fn push_u16str(&mut self, s: &U16Str) {
+        let units = s.as_slice();
+        match (self.trailing_high_surrogate(), units.first()) {
+            (Some(high), Some(&low)) if is_utf16_low_surrogate(low) => {
+                self.bytes.truncate(self.bytes.len() - 3);
+                let units = core::iter::once(high).chain(units.iter().copied());
+                encode_wtf8(units, &mut self.bytes);
+            }
+            _ => encode_wtf8(units.iter().copied(), &mut self.bytes),
+        }
+    }
+
+    /// Appends a WTF-8 string slice to the end of this string.
+    ///
+    /// If this string ends with a high surrogate and `s` starts with a low surrogate, they are
+    /// joined into a single code point.
+    pub fn push_wtf8_str(&mut self, s: &Wtf8Str) {
+        match (
+            self.trailing_high_surrogate(),
+            decode_surrogate(s.as_bytes()),
+        ) {
+            (Some(high), Some(low)) if is_utf16_low_surrogate(low) => {
+                self.bytes.truncate(self.bytes.len() - 3);
+                encode_wtf8([high, low], &mut self.bytes);
+                self.bytes.extend_from_slice(&s.as_bytes()[3..]);
+            }
+            _ => self.bytes.extend_from_slice(s.as_bytes()),
+        }
+    }
+
+    /// Converts this string to a [`String`] if it contains no unpaired surrogates.
+    ///
+    /// # Errors
+    ///
+    /// Returns this string unchanged if it contains unpaired surrogates.
+    pub fn into_string(self) -> Result<String, Self> {
+        match String::from_utf8(self.bytes) {
+            Ok(s) => Ok(s),
+            Err(e) => Err(Self {
+                bytes: e.into_bytes(),
+            }),
+        }
+    }
+
+    /// Converts this string into its underlying bytes.
+    #[inline]
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Returns the capacity of this string in bytes.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.bytes.capacity()
+    }
+
+    /// Truncates this string, removing all contents.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bytes.clear()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<[u8]> for Wtf8Buf {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<Wtf8Str> for Wtf8Buf {
+    #[inline]
+    fn as_ref(&self) -> &Wtf8Str {
+        self.as_wtf8_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Borrow<Wtf8Str> for Wtf8Buf {
+    #[inline]
+    fn borrow(&self) -> &Wtf8Str {
+        self.as_wtf8_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for Wtf8Buf {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_wtf8_str(), f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Deref for Wtf8Buf {
+    type Target = Wtf8Str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_wtf8_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for Wtf8Buf {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_wtf8_str(), f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&str> for Wtf8Buf {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self {
+            bytes: value.as_bytes().to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<String> for Wtf8Buf {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self {
+            bytes: value.into_bytes(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&Wtf8Str> for Wtf8Buf {
+    #[inline]
+    fn from(value: &Wtf8Str) -> Self {
+        value.to_owned()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&U16Str> for Wtf8Buf {
+    #[inline]
+    fn from(value: &U16Str) -> Self {
+        Self::from_u16str(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&U16String> for Wtf8Buf {
+    #[inline]
+    fn from(value: &U16String) -> Self {
+        Self::from_u16str(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&Wtf8Str> for U16String {
+    #[inline]
+    fn from(value: &Wtf8Str) -> Self {
+        value.to_u16string()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Wtf8Buf> for Vec<u8> {
+    #[inline]
+    fn from(value: Wtf8Buf) -> Self {
+        value.into_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<str> for Wtf8Buf {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<&str> for Wtf8Buf {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<Wtf8Str> for Wtf8Buf {
+    #[inline]
+    fn eq(&self, other: &Wtf8Str) -> bool {
+        self.as_wtf8_str() == other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<&Wtf8Str> for Wtf8Buf {
+    #[inline]
+    fn eq(&self, other: &&Wtf8Str) -> bool {
+        self.as_wtf8_str() == *other
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn from_bytes() {
+        assert!(Wtf8Str::from_bytes(b"a\xED\xA0\x80\xED\xB0\x80").is_err());
+        assert!(Wtf8Str::from_bytes(b"\xED\xB0\x80\xED\xA0\x80").is_ok());
+        assert!(Wtf8Str::from_bytes(b"\xED\xA0\x80a\xED\xB0\x80").is_ok());
+        assert_eq!(
+            Wtf8Str::from_bytes(b"ab\xED\xA0")
+                .unwrap_err()
+                .valid_up_to(),
+            2
+        );
+        assert_eq!(Wtf8Str::from_bytes(b"a\xF0\x9F\x92\x96").unwrap(), "a💖");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn u16_round_trip() {
+        use alloc::{format, string::ToString};
+
+        let wide = U16String::from_vec([0xDC00, 0xD83D, 0xDC96, 0x61, 0xD800]);
+        let wtf8 = Wtf8Buf::from(&wide);
+        assert_eq!(
+            wtf8.as_bytes(),
+            b"\xED\xB0\x80\xF0\x9F\x92\x96a\xED\xA0\x80"
+        );
+        assert_eq!(wtf8.to_u16string(), wide);
+        assert_eq!(wtf8.to_string(), "\u{FFFD}💖a\u{FFFD}");
+        assert_eq!(format!("{:?}", wtf8), r#""\u{dc00}💖a\u{d800}""#);
+        assert!(wtf8.clone().into_string().is_err());
+
+        let mut wtf8 = Wtf8Buf::from(U16Str::from_slice(&[0x61, 0xD83D]));
+        wtf8.push_wtf8_str(&Wtf8Buf::from(U16Str::from_slice(&[0xDC96])));
+        assert_eq!(wtf8.into_string().unwrap(), "a💖");
+    }
+}